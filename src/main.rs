@@ -14,9 +14,9 @@ use shiny_dex::prelude::*;
 mod pokemon_finder;
 use pokemon_finder::prelude::*;
 mod google_analytics;
+mod time;
 
 pub static BASE_GRAPHQL_API_URL: &str = "https://beta.pokeapi.co/graphql/v1beta";
-pub static BASE_REST_API_URL: &str = "https://pokeapi.co/api/v2";
 
 #[derive(Clone, Routable)]
 #[rustfmt::skip]
@@ -29,6 +29,16 @@ enum Route {
             ShinyDex {},
             #[route("/favourites")]
             Favourites {},
+            #[route("/inventory")]
+            SandwichInventory {},
+            #[route("/planner")]
+            HuntPlanner {},
+            #[route("/outbreaks")]
+            OutbreakAssistant {},
+            #[route("/backup")]
+            ImportExport {},
+            #[route("/sync")]
+            DeviceSync {},
         #[end_nest]
         #[nest("/finder")]
             #[route("/")]
@@ -42,6 +52,9 @@ enum Route {
 
 #[component]
 fn NavBar() -> Element {
+    use_storage_provider();
+    use_sync_provider();
+
     rsx! {
         nav {
             display: "flex",
@@ -54,6 +67,9 @@ fn NavBar() -> Element {
             Link { to: "/", "Home" }
             Link { to: "/shiny", "Shiny Dex" }
             Link { to: "/finder", "Pokémon Finder" }
+            ProfileSwitcher {}
+            MealTimerBadge {}
+            SyncBadge {}
         }
         Outlet::<Route> {}
     }
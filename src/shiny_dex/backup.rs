@@ -0,0 +1,481 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::footer;
+use crate::shiny_dex::favourites::{Collection, Collections, FavouriteEntry};
+use crate::shiny_dex::hunt::{Hunt, Hunts};
+use crate::shiny_dex::storage::{use_stored, CollectionsKey, HuntsKey};
+use crate::time::{format_iso, now};
+
+/// Bump when the backup format changes, and teach `parse_backup` to read the old one.
+pub const BACKUP_VERSION: u64 = 1;
+
+/// Everything worth keeping if the browser's storage is cleared.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Backup {
+    pub version: u64,
+    pub exported_at: f64,
+    pub collections: Collections,
+    pub hunts: Hunts,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ImportMode {
+    Merge,
+    Replace,
+}
+
+/// Parses and validates a backup file, listing every problem found rather
+/// than stopping at the first.
+pub fn parse_backup(text: &str) -> Result<Backup, Vec<String>> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|err| vec![format!("Not a JSON file: {err}")])?;
+    let Some(version) = value.get("version").and_then(Value::as_u64) else {
+        return Err(vec![
+            "Missing a \"version\" number, this doesn't look like a backup file".to_string(),
+        ]);
+    };
+    if version == 0 {
+        return Err(vec![
+            "Backup version 0 doesn't exist, the file is damaged".to_string()
+        ]);
+    }
+    if version > BACKUP_VERSION {
+        return Err(vec![format!(
+            "The file is backup version {version}, but this site only reads up to version {BACKUP_VERSION}"
+        )]);
+    }
+
+    let mut errors = Vec::new();
+    let mut collections = Collections::new();
+    match value.get("collections") {
+        Some(Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate() {
+                match serde_json::from_value::<Collection>(item.clone()) {
+                    Ok(collection) => collections.push(collection),
+                    Err(err) => errors.push(format!("collections[{i}]: {err}")),
+                }
+            }
+        }
+        Some(_) => errors.push("collections: expected a list".to_string()),
+        None => errors.push("collections: missing from the file".to_string()),
+    }
+    let mut hunts = Hunts::new();
+    match value.get("hunts") {
+        Some(Value::Object(items)) => {
+            for (pokemon, item) in items {
+                match serde_json::from_value::<Hunt>(item.clone()) {
+                    Ok(hunt) => {
+                        hunts.insert(pokemon.clone(), hunt);
+                    }
+                    Err(err) => errors.push(format!("hunts.{pokemon}: {err}")),
+                }
+            }
+        }
+        Some(_) => errors.push("hunts: expected an object keyed by pokemon".to_string()),
+        None => errors.push("hunts: missing from the file".to_string()),
+    }
+
+    for (i, collection) in collections.iter().enumerate() {
+        if collection.name.trim().is_empty() {
+            errors.push(format!("collections[{i}]: the name is empty"));
+        }
+        if collections[..i].iter().any(|c| c.name == collection.name) {
+            errors.push(format!(
+                "collections[{i}]: \"{}\" appears more than once",
+                collection.name
+            ));
+        }
+        for (j, entry) in collection.entries.iter().enumerate() {
+            if entry.pokemon.trim().is_empty() {
+                errors.push(format!(
+                    "collections[{i}].entries[{j}]: the pokemon is empty"
+                ));
+            }
+        }
+    }
+    for (pokemon, hunt) in &hunts {
+        if hunt.encounters < 0 {
+            errors.push(format!("hunts.{pokemon}: encounters can't be negative"));
+        }
+        if !(0..=3).contains(&hunt.sandwich_level) {
+            errors.push(format!("hunts.{pokemon}: sandwich level must be 0 to 3"));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(Backup {
+        version,
+        exported_at: value
+            .get("exported_at")
+            .and_then(Value::as_f64)
+            .unwrap_or_default(),
+        collections,
+        hunts,
+    })
+}
+
+/// Adds collections and entries that aren't already there, matching
+/// collections by name. Hunts in both keep whichever has more encounters.
+fn merge(collections: &mut Collections, hunts: &mut Hunts, backup: Backup) {
+    for imported in backup.collections {
+        match collections.iter_mut().find(|c| c.name == imported.name) {
+            Some(collection) => {
+                for entry in imported.entries {
+                    if !collection.contains(&entry.pokemon) {
+                        collection.entries.push(entry);
+                    }
+                }
+            }
+            None => collections.push(imported),
+        }
+    }
+    for (pokemon, hunt) in backup.hunts {
+        match hunts.get(&pokemon) {
+            Some(existing) if existing.encounters >= hunt.encounters => {}
+            _ => {
+                hunts.insert(pokemon, hunt);
+            }
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A row per collection entry, with its hunt's progress, then a row per hunt
+/// for pokemon that aren't in any collection.
+fn to_csv(collections: &Collections, hunts: &Hunts) -> String {
+    let mut csv = String::from(
+        "collection,pokemon,priority,target_method,note,encounters,hunt_method,started_at,completed_at\n",
+    );
+    let mut row = |collection: &str, entry: &FavouriteEntry, hunt: Option<&Hunt>| {
+        let fields = [
+            collection.to_string(),
+            entry.pokemon.clone(),
+            entry.priority.label().to_string(),
+            entry
+                .method
+                .map(|m| m.label())
+                .unwrap_or_default()
+                .to_string(),
+            entry.note.clone(),
+            hunt.map(|h| h.encounters.to_string()).unwrap_or_default(),
+            hunt.map(|h| h.method.label().to_string())
+                .unwrap_or_default(),
+            hunt.map(|h| format_iso(h.started_at)).unwrap_or_default(),
+            hunt.and_then(|h| h.completed_at)
+                .map(format_iso)
+                .unwrap_or_default(),
+        ];
+        let fields = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    };
+    for collection in collections {
+        for entry in &collection.entries {
+            row(&collection.name, entry, hunts.get(&entry.pokemon));
+        }
+    }
+    let mut loose = hunts
+        .iter()
+        .filter(|(pokemon, _)| !collections.iter().any(|c| c.contains(pokemon)))
+        .collect::<Vec<_>>();
+    loose.sort_by(|a, b| a.0.cmp(b.0));
+    for (pokemon, hunt) in loose {
+        row("", &FavouriteEntry::new(pokemon), Some(hunt));
+    }
+    csv
+}
+
+/// Has the browser save `contents` as a file.
+pub fn download(filename: &str, mime: &str, contents: &str) {
+    let filename = serde_json::to_string(filename).unwrap_or_default();
+    let mime = serde_json::to_string(mime).unwrap_or_default();
+    let contents = serde_json::to_string(contents).unwrap_or_default();
+    document::eval(&format!(
+        "const link = document.createElement('a');
+        link.href = URL.createObjectURL(new Blob([{contents}], {{ type: {mime} }}));
+        link.download = {filename};
+        link.click();
+        // Revoking straight away can cancel the download in some browsers.
+        setTimeout(() => URL.revokeObjectURL(link.href), 1000);"
+    ));
+}
+
+#[component]
+pub fn ImportExport() -> Element {
+    let mut collections = use_stored::<CollectionsKey>();
+    let mut hunts = use_stored::<HuntsKey>();
+    let mut pending = use_signal(|| None::<Backup>);
+    let mut errors = use_signal(Vec::<String>::new);
+    let mut status = use_signal(String::new);
+
+    let export_json = move |_| {
+        let backup = Backup {
+            version: BACKUP_VERSION,
+            exported_at: now(),
+            collections: collections(),
+            hunts: hunts(),
+        };
+        match serde_json::to_string_pretty(&backup) {
+            Ok(json) => download("shiny-dex-backup.json", "application/json", &json),
+            Err(err) => errors.set(vec![format!("Couldn't export: {err}")]),
+        }
+    };
+    let export_csv = move |_| {
+        download(
+            "shiny-dex-backup.csv",
+            "text/csv",
+            &to_csv(&collections.read(), &hunts.read()),
+        );
+    };
+
+    let read_file = move |e: FormEvent| async move {
+        pending.set(None);
+        errors.set(Vec::new());
+        status.set(String::new());
+        let Some(files) = e.files() else {
+            return;
+        };
+        let Some(name) = files.files().into_iter().next() else {
+            return;
+        };
+        match files.read_file_to_string(&name).await {
+            Some(text) => match parse_backup(&text) {
+                Ok(backup) => pending.set(Some(backup)),
+                Err(report) => errors.set(report),
+            },
+            None => errors.set(vec![format!("Couldn't read {name}")]),
+        }
+    };
+
+    let mut import = move |mode: ImportMode| {
+        let Some(backup) = pending.take() else {
+            return;
+        };
+        match mode {
+            ImportMode::Merge => merge(&mut collections.write(), &mut hunts.write(), backup),
+            ImportMode::Replace => {
+                collections.set(backup.collections);
+                hunts.set(backup.hunts);
+            }
+        }
+        status.set(match mode {
+            ImportMode::Merge => "Merged the backup into your data".to_string(),
+            ImportMode::Replace => "Replaced your data with the backup".to_string(),
+        });
+    };
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", justify_content: "space-between",
+            h1 { "Import and export" }
+            h1 { a { href: "/shiny", "Back to the shiny dex" } }
+        }
+        p {
+            "Your favourites and hunts only live in this browser. Export them now and then "
+            "so clearing the browser doesn't lose them."
+        }
+        h2 { "Export" }
+        div { display: "flex", flex_direction: "row",
+            button {
+                class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                margin: "5px",
+                onclick: export_json,
+                "Download backup (JSON)"
+            }
+            button {
+                class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                margin: "5px",
+                onclick: export_csv,
+                "Download spreadsheet (CSV)"
+            }
+        }
+        p { "Only JSON backups can be imported again, the CSV is for spreadsheets." }
+        h2 { "Import" }
+        input { r#type: "file", accept: ".json,application/json", onchange: read_file }
+        if let Some(backup) = pending() {
+            p {
+                "The backup has {backup.collections.len()} collections with "
+                "{backup.collections.iter().map(|c| c.entries.len()).sum::<usize>()} entries, "
+                "and {backup.hunts.len()} hunts."
+            }
+            div { display: "flex", flex_direction: "row",
+                button {
+                    class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                    margin: "5px",
+                    title: "Keep your data and add anything new from the backup",
+                    onclick: move |_| import(ImportMode::Merge),
+                    "Merge"
+                }
+                button {
+                    class: "bg-red-500 hover:bg-red-700 text-white font-bold py-2 px-4 rounded",
+                    margin: "5px",
+                    title: "Throw away your data and use the backup's",
+                    onclick: move |_| import(ImportMode::Replace),
+                    "Replace"
+                }
+            }
+        }
+        if !errors.read().is_empty() {
+            div { color: "#D32F2F",
+                p { "The file can't be imported:" }
+                ul {
+                    for error in errors() {
+                        li { "{error}" }
+                    }
+                }
+            }
+        }
+        if !status.read().is_empty() {
+            p { "{status}" }
+        }
+        footer::Footer {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hunt(encounters: i64) -> Hunt {
+        Hunt {
+            encounters,
+            ..Default::default()
+        }
+    }
+
+    fn collection(name: &str, pokemon: &[&str]) -> Collection {
+        Collection {
+            name: name.to_string(),
+            entries: pokemon.iter().map(|p| FavouriteEntry::new(p)).collect(),
+        }
+    }
+
+    #[test]
+    fn valid_backups_parse() {
+        let text = json!({
+            "version": 1,
+            "exported_at": 10.0,
+            "collections": [collection("Favourites", &["eevee"])],
+            "hunts": { "ralts": hunt(12) },
+        })
+        .to_string();
+
+        let backup = parse_backup(&text).unwrap();
+
+        assert_eq!(backup.exported_at, 10.0);
+        assert_eq!(
+            backup.collections,
+            vec![collection("Favourites", &["eevee"])]
+        );
+        assert_eq!(backup.hunts["ralts"].encounters, 12);
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let mut negative = hunt(-1);
+        negative.sandwich_level = 4;
+        let text = json!({
+            "version": 1,
+            "collections": [
+                collection("Favourites", &["eevee"]),
+                collection("Favourites", &[""]),
+                { "name": 3 },
+            ],
+            "hunts": { "ralts": negative, "gible": "lots" },
+        })
+        .to_string();
+
+        let mut errors = parse_backup(&text).unwrap_err();
+        errors.sort();
+
+        assert_eq!(errors.len(), 6, "{errors:?}");
+        assert_eq!(errors[0], "collections[1].entries[0]: the pokemon is empty");
+        assert_eq!(
+            errors[1],
+            "collections[1]: \"Favourites\" appears more than once"
+        );
+        assert!(errors[2].starts_with("collections[2]: "));
+        assert!(errors[3].starts_with("hunts.gible: "));
+        assert_eq!(errors[4], "hunts.ralts: encounters can't be negative");
+        assert_eq!(errors[5], "hunts.ralts: sandwich level must be 0 to 3");
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        for version in [json!(0), json!(BACKUP_VERSION + 1), json!("1"), json!(null)] {
+            let text = json!({ "version": version, "collections": [], "hunts": {} }).to_string();
+            assert!(
+                parse_backup(&text).is_err(),
+                "version {version} was accepted"
+            );
+        }
+        assert!(parse_backup("not json").is_err());
+    }
+
+    #[test]
+    fn missing_sections_are_reported() {
+        let mut errors = parse_backup(&json!({ "version": 1 }).to_string()).unwrap_err();
+        errors.sort();
+        assert_eq!(
+            errors,
+            vec![
+                "collections: missing from the file",
+                "hunts: missing from the file"
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_adds_what_is_missing_and_keeps_the_longer_hunt() {
+        let mut collections = vec![collection("Favourites", &["eevee"])];
+        let mut hunts = Hunts::from([
+            ("ralts".to_string(), hunt(10)),
+            ("gible".to_string(), hunt(5)),
+        ]);
+        let backup = Backup {
+            version: BACKUP_VERSION,
+            exported_at: 0.0,
+            collections: vec![
+                collection("Favourites", &["eevee", "pikachu"]),
+                collection("Trade", &["sprigatito"]),
+            ],
+            hunts: Hunts::from([
+                ("ralts".to_string(), hunt(3)),
+                ("gible".to_string(), hunt(8)),
+                ("zorua".to_string(), hunt(1)),
+            ]),
+        };
+
+        merge(&mut collections, &mut hunts, backup);
+
+        assert_eq!(
+            collections,
+            vec![
+                collection("Favourites", &["eevee", "pikachu"]),
+                collection("Trade", &["sprigatito"]),
+            ]
+        );
+        assert_eq!(hunts["ralts"].encounters, 10);
+        assert_eq!(hunts["gible"].encounters, 8);
+        assert_eq!(hunts["zorua"].encounters, 1);
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("eevee"), "eevee");
+        assert_eq!(csv_field("jolly, timid"), "\"jolly, timid\"");
+        assert_eq!(csv_field("the \"one\""), "\"the \"\"one\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+}
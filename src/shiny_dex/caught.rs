@@ -0,0 +1,102 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub static BALLS: [&str; 24] = [
+    "poke-ball",
+    "great-ball",
+    "ultra-ball",
+    "master-ball",
+    "premier-ball",
+    "luxury-ball",
+    "heal-ball",
+    "net-ball",
+    "dive-ball",
+    "nest-ball",
+    "repeat-ball",
+    "timer-ball",
+    "quick-ball",
+    "dusk-ball",
+    "level-ball",
+    "lure-ball",
+    "moon-ball",
+    "friend-ball",
+    "love-ball",
+    "heavy-ball",
+    "fast-ball",
+    "sport-ball",
+    "dream-ball",
+    "beast-ball",
+];
+
+/// A shiny the user owns. An empty `form` means the default form.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CaughtEntry {
+    pub form: String,
+    pub ball: String,
+}
+
+impl Default for CaughtEntry {
+    fn default() -> Self {
+        Self {
+            form: String::new(),
+            ball: BALLS[0].to_string(),
+        }
+    }
+}
+
+pub type Caught = HashMap<String, CaughtEntry>;
+
+pub fn toggle_caught(mut caught: Signal<Caught>, pokemon: &str) {
+    if caught.read().contains_key(pokemon) {
+        caught.write().remove(pokemon);
+    } else {
+        caught
+            .write()
+            .insert(pokemon.to_string(), CaughtEntry::default());
+    }
+}
+
+#[component]
+pub fn OwnedPanel(pokemon: ReadOnlySignal<String>, caught: Signal<Caught>) -> Element {
+    let entry = caught.read().get(&pokemon()).cloned();
+
+    rsx! {
+        p {
+            "Owned: ",
+            input {
+                r#type: "checkbox",
+                oninput: move |_| toggle_caught(caught, &pokemon()),
+                checked: caught.read().contains_key(&pokemon())
+            }
+        }
+        if let Some(entry) = entry {
+            p {
+                "Ball: ",
+                select {
+                    oninput: move |e| {
+                        if let Some(entry) = caught.write().get_mut(&pokemon()) {
+                            entry.ball = e.data.value();
+                        }
+                    },
+                    for ball in BALLS.iter() {
+                        option { value: *ball, selected: entry.ball == *ball, "{ball}" }
+                    }
+                }
+            }
+            p {
+                "Form: ",
+                input {
+                    r#type: "text",
+                    placeholder: "default",
+                    value: "{entry.form}",
+                    oninput: move |e| {
+                        if let Some(entry) = caught.write().get_mut(&pokemon()) {
+                            entry.form = e.data.value();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
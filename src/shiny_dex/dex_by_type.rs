@@ -1,21 +1,31 @@
-use std::collections::HashSet;
-
+use crate::shiny_dex::caught::{toggle_caught, Caught};
+use crate::shiny_dex::exclusives::{load_exclusives, use_my_game, Version, VersionBadge};
+use crate::shiny_dex::favourites::{is_favourite, toggle_favourite, Collections};
 use crate::shiny_dex::focus::{dex_by_type, load_focus, perform_gql_query, Focus, FocusState};
+use crate::shiny_dex::hunt::Hunts;
+use crate::shiny_dex::shiny_dex::TYPES;
+use crate::shiny_dex::shiny_lock::{is_shiny_locked, shiny_lock};
+use crate::shiny_dex::storage::{use_stored, CollectionsKey, HuntsKey};
 use crate::shiny_dex::TYPES_INFO;
+use crate::time::now;
 use dioxus::prelude::*;
-use dioxus_logger::tracing::info;
-use dioxus_sdk::storage::use_persistent;
 
 #[component]
-pub fn DexByType(dex: Signal<String>, pokemon_type: Signal<String>) -> Element {
+pub fn DexByType(
+    dex: Signal<String>,
+    pokemon_types: Signal<Vec<String>>,
+    caught: Signal<Caught>,
+    missing_only: ReadOnlySignal<bool>,
+) -> Element {
     let focus_state = use_signal(|| FocusState::Unset);
+    let hunts = use_stored::<HuntsKey>();
 
     rsx! {
         div { display: "flex", flex_direction: "row",
-            div { overflow: "auto", max_height: "100vh", margin: "10px", width: "20%",
-                Search { focus_state: focus_state, dex: dex.clone(), pokemon_type: pokemon_type.clone() }
+            div { overflow: "auto", max_height: "100vh", margin: "10px", width: "40%",
+                Search { focus_state: focus_state, dex: dex.clone(), pokemon_types, caught, hunts, missing_only }
             }
-            div { margin: "10px", width: "80%", Focus { focus_state } }
+            div { margin: "10px", width: "60%", Focus { focus_state, caught, hunts } }
         }
     }
 }
@@ -24,21 +34,24 @@ pub fn DexByType(dex: Signal<String>, pokemon_type: Signal<String>) -> Element {
 struct SearchProps {
     focus_state: Signal<FocusState>,
     dex: Signal<String>,
-    pokemon_type: Signal<String>,
+    pokemon_types: Signal<Vec<String>>,
+    caught: Signal<Caught>,
+    hunts: Signal<Hunts>,
+    missing_only: ReadOnlySignal<bool>,
 }
 
 fn Search(props: SearchProps) -> Element {
     let pokemon = use_resource(move || async move {
         let variables = dex_by_type::Variables {
             dex: props.dex.to_string().clone(),
-            pokemon_type: props.pokemon_type.to_string().clone(),
+            pokemon_types: (props.pokemon_types)(),
         };
         perform_gql_query(variables).await
     });
 
     match &*pokemon.read_unchecked() {
         Some(Ok(pokemon)) => {
-            rsx! { RenderDex { focus_state: props.focus_state, pokemon: pokemon.clone(), pokemon_type: props.pokemon_type.clone() } }
+            rsx! { RenderDex { focus_state: props.focus_state, pokemon: pokemon.clone(), pokemon_types: props.pokemon_types, caught: props.caught, hunts: props.hunts, missing_only: props.missing_only } }
         }
         Some(Err(err)) => rsx! {"An error occurred while loading {err}"},
         _ => rsx! {"Loading items"},
@@ -49,67 +62,440 @@ fn Search(props: SearchProps) -> Element {
 struct RenderDexProps {
     focus_state: Signal<FocusState>,
     pokemon: ReadOnlySignal<Vec<dex_by_type::DexByTypePokemonV2Pokemon>>,
-    pokemon_type: String,
+    pokemon_types: ReadOnlySignal<Vec<String>>,
+    caught: Signal<Caught>,
+    hunts: Signal<Hunts>,
+    missing_only: ReadOnlySignal<bool>,
 }
 
 #[component]
 fn RenderDex(props: RenderDexProps) -> Element {
     rsx! {
-        div { overflow: "hidden", background_color: TYPES_INFO.get(props.pokemon_type.as_str()).unwrap().color, border_radius: "50%", width: "100px", height: "100px", img { src: "/icons/{props.pokemon_type.clone()}.svg" } }
-        div { overflow: "auto", display: "flex", flex_direction: "column", width: "100%", DexTable { focus_state: props.focus_state, pokemon: props.pokemon } }
+        if let [pokemon_type] = &props.pokemon_types.read()[..] {
+            div { overflow: "hidden", background_color: TYPES_INFO.get(pokemon_type.as_str()).unwrap().color, border_radius: "50%", width: "100px", height: "100px", img { src: "/icons/{pokemon_type}.svg" } }
+        }
+        div { overflow: "auto", display: "flex", flex_direction: "column", width: "100%", DexTable { focus_state: props.focus_state, pokemon: props.pokemon, pokemon_types: props.pokemon_types, caught: props.caught, hunts: props.hunts, missing_only: props.missing_only } }
+    }
+}
+
+type Entry = dex_by_type::DexByTypePokemonV2Pokemon;
+
+fn dex_number(entry: &Entry) -> i64 {
+    entry
+        .pokemon_v2_pokemonspecy
+        .as_ref()
+        .and_then(|s| s.pokemon_v2_pokemondexnumbers.first())
+        .map(|n| n.pokedex_number)
+        .unwrap_or_default()
+}
+
+fn types(entry: &Entry) -> Vec<String> {
+    entry
+        .pokemon_v2_pokemontypes
+        .iter()
+        .filter_map(|t| t.pokemon_v2_type.as_ref().map(|t| t.name.clone()))
+        .collect()
+}
+
+fn base_stat_total(entry: &Entry) -> i64 {
+    entry
+        .pokemon_v2_pokemonstats
+        .iter()
+        .map(|s| s.base_stat)
+        .sum()
+}
+
+fn capture_rate(entry: &Entry) -> i64 {
+    entry
+        .pokemon_v2_pokemonspecy
+        .as_ref()
+        .and_then(|s| s.capture_rate)
+        .unwrap_or_default()
+}
+
+fn has_shiny_sprite(entry: &Entry) -> bool {
+    entry.pokemon_v2_pokemonsprites.iter().any(|s| {
+        s.sprites.get("front_shiny").is_some_and(|s| s.is_string())
+            || s.sprites
+                .get("other")
+                .and_then(|o| o.get("official-artwork"))
+                .and_then(|a| a.get("front_shiny"))
+                .is_some_and(|s| s.is_string())
+    })
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum SortColumn {
+    #[default]
+    Number,
+    Name,
+    Types,
+    BaseStatTotal,
+    CaptureRate,
+}
+
+impl SortColumn {
+    const ALL: [SortColumn; 5] = [
+        SortColumn::Number,
+        SortColumn::Name,
+        SortColumn::Types,
+        SortColumn::BaseStatTotal,
+        SortColumn::CaptureRate,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SortColumn::Number => "#",
+            SortColumn::Name => "Pokemon Name",
+            SortColumn::Types => "Types",
+            SortColumn::BaseStatTotal => "BST",
+            SortColumn::CaptureRate => "Capture Rate",
+        }
+    }
+
+    fn sort(&self, entries: &mut [Entry]) {
+        match self {
+            SortColumn::Number => entries.sort_by_key(dex_number),
+            SortColumn::Name => entries.sort_by(|a, b| a.name.cmp(&b.name)),
+            SortColumn::Types => entries.sort_by_key(types),
+            SortColumn::BaseStatTotal => entries.sort_by_key(base_stat_total),
+            SortColumn::CaptureRate => entries.sort_by_key(capture_rate),
+        }
     }
 }
 
+type Cursor = Option<(&'static str, String)>;
+
+fn row_id(pokemon_type: &str, name: &str) -> String {
+    format!("dex-row-{pokemon_type}-{name}")
+}
+
+fn focus_element(id: &str) {
+    let id = serde_json::to_string(id).unwrap_or_default();
+    document::eval(&format!("document.getElementById({id})?.focus();"));
+}
+
+fn scroll_to_element(id: &str) {
+    let id = serde_json::to_string(id).unwrap_or_default();
+    document::eval(&format!(
+        "document.getElementById({id})?.scrollIntoView({{ block: 'nearest' }});"
+    ));
+}
+
 #[component]
 fn DexTable(
     focus_state: Signal<FocusState>,
-    pokemon: ReadOnlySignal<Vec<dex_by_type::DexByTypePokemonV2Pokemon>>,
+    pokemon: ReadOnlySignal<Vec<Entry>>,
+    pokemon_types: ReadOnlySignal<Vec<String>>,
+    caught: Signal<Caught>,
+    hunts: Signal<Hunts>,
+    missing_only: ReadOnlySignal<bool>,
 ) -> Element {
-    let mut faves = use_persistent("faves", || HashSet::<String>::new());
+    let faves = use_stored::<CollectionsKey>();
+    let mut sort = use_signal(|| (SortColumn::default(), true));
+    let mut filter = use_signal(String::new);
+    let mut faves_only = use_signal(|| false);
+    let mut shiny_sprite_only = use_signal(|| false);
+    let mut hide_locked = use_signal(|| false);
+    let mut cursor = use_signal(|| Cursor::None);
+    let my_game = use_my_game();
+
+    let exclusives = use_resource(move || {
+        load_exclusives(pokemon.read().iter().map(|e| e.name.clone()).collect())
+    });
+    let exclusive = move |name: &str| {
+        exclusives
+            .read()
+            .as_ref()
+            .and_then(|exclusives| exclusives.get(name).copied())
+    };
+
+    let rows = use_memo(move || {
+        let filter = filter().to_lowercase();
+        let mut rows = pokemon()
+            .into_iter()
+            .filter(|e| e.name.contains(&filter))
+            .filter(|e| !missing_only() || !caught.read().contains_key(&e.name))
+            .filter(|e| !faves_only() || is_favourite(&faves.read(), &e.name))
+            .filter(|e| !shiny_sprite_only() || has_shiny_sprite(e))
+            .filter(|e| !hide_locked() || !is_shiny_locked(&e.name))
+            .collect::<Vec<_>>();
+        let (column, ascending) = sort();
+        column.sort(&mut rows);
+        if !ascending {
+            rows = reverse_groups(rows);
+        }
+        rows
+    });
+
+    // Uncaught pokemon only found in the other game.
+    let trade_needed = use_memo(move || {
+        let Some(my_game) = my_game() else {
+            return Vec::new();
+        };
+        rows()
+            .into_iter()
+            .filter(|e| exclusive(&e.name).is_some_and(|v| v != my_game))
+            .filter(|e| !caught.read().contains_key(&e.name))
+            .map(|e| e.name)
+            .collect::<Vec<_>>()
+    });
+
+    // A section per selected type, in `TYPES` order; dual types appear in both.
+    let sections = use_memo(move || {
+        TYPES
+            .iter()
+            .filter(|t| pokemon_types.read().iter().any(|p| p == *t))
+            .map(|t| (*t, section(&rows.read(), t)))
+            .collect::<Vec<_>>()
+    });
+
+    let mut adjust_hunt = move |encounter: bool| {
+        let FocusState::Loaded(focus_data) = &*focus_state.peek() else {
+            return;
+        };
+        if let Some(hunt) = hunts.write().get_mut(focus_data.name()) {
+            if encounter {
+                hunt.encounter(now());
+            } else {
+                hunt.undo_encounter();
+            }
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let visible = sections
+            .read()
+            .iter()
+            .flat_map(|(t, rows)| rows.iter().map(move |(entry, _)| (*t, entry.clone())))
+            .collect::<Vec<_>>();
+        let position = cursor.read().as_ref().and_then(|(t, name)| {
+            visible
+                .iter()
+                .position(|(pokemon_type, entry)| pokemon_type == t && &entry.name == name)
+        });
+        let selected = position.map(|i| visible[i].1.clone());
+
+        match e.key() {
+            Key::ArrowDown | Key::ArrowUp => {
+                e.prevent_default();
+                let next = match (e.key(), position) {
+                    (Key::ArrowDown, Some(i)) => (i + 1).min(visible.len().saturating_sub(1)),
+                    (Key::ArrowUp, Some(i)) => i.saturating_sub(1),
+                    _ => 0,
+                };
+                if let Some((pokemon_type, entry)) = visible.get(next) {
+                    cursor.set(Some((pokemon_type, entry.name.clone())));
+                    scroll_to_element(&row_id(pokemon_type, &entry.name));
+                }
+            }
+            Key::Enter => {
+                if let Some(entry) = selected {
+                    spawn(load_focus(focus_state, entry));
+                }
+            }
+            Key::Character(c) if c.eq_ignore_ascii_case("f") => {
+                if let Some(entry) = selected {
+                    toggle_favourite(faves, &entry.name);
+                }
+            }
+            Key::Character(c) if c == "/" => {
+                e.prevent_default();
+                focus_element("dex-filter");
+            }
+            Key::Character(c) if c == "+" || c == "=" => adjust_hunt(true),
+            Key::Character(c) if c == "-" => adjust_hunt(false),
+            _ => {}
+        }
+    };
 
     rsx! {
-        table { border_collapse: "collapse",
+        div { display: "flex", flex_direction: "row", flex_wrap: "wrap", align_items: "center",
+            input {
+                id: "dex-filter",
+                r#type: "text",
+                placeholder: "Filter by name",
+                value: "{filter}",
+                oninput: move |e| filter.set(e.data.value()),
+                // Enter or Escape hands the keyboard back to the table.
+                onkeydown: move |e| {
+                    if matches!(e.key(), Key::Enter | Key::Escape) {
+                        focus_element("dex-table");
+                    }
+                },
+            }
+            label { class: "py-2 px-4",
+                input {
+                    r#type: "checkbox",
+                    oninput: move |_| faves_only.set(!faves_only()),
+                    checked: faves_only()
+                }
+                " Favourites only"
+            }
+            label { class: "py-2 px-4",
+                input {
+                    r#type: "checkbox",
+                    oninput: move |_| shiny_sprite_only.set(!shiny_sprite_only()),
+                    checked: shiny_sprite_only()
+                }
+                " Has shiny sprite"
+            }
+            label { class: "py-2 px-4",
+                input {
+                    r#type: "checkbox",
+                    oninput: move |_| hide_locked.set(!hide_locked()),
+                    checked: hide_locked()
+                }
+                " Hide shiny locked"
+            }
+        }
+        p { font_size: "small",
+            "Keys: ↑/↓ move, Enter focus, F favourite, / search, +/- hunt counter"
+        }
+        table { id: "dex-table", tabindex: "0", border_collapse: "collapse", onkeydown,
             thead {
-                tr { th { "Pokemon Name" } }
+                tr {
+                    for column in SortColumn::ALL {
+                        th {
+                            cursor: "pointer",
+                            onclick: move |_| {
+                                let (current, ascending) = sort();
+                                sort.set((column, current != column || !ascending));
+                            },
+                            "{column.label()}"
+                            if sort().0 == column {
+                                if sort().1 { " ▲" } else { " ▼" }
+                            }
+                        }
+                    }
+                    th {}
+                }
+            }
+            for (pokemon_type, rows) in sections() {
+                tbody {
+                    if pokemon_types.read().len() > 1 {
+                        tr {
+                            td { colspan: "6",
+                                div { display: "flex", flex_direction: "row", align_items: "center", padding: "5px",
+                                    div { overflow: "hidden", background_color: TYPES_INFO.get(pokemon_type).unwrap().color, border_radius: "50%", width: "30px", height: "30px", margin_right: "10px", img { src: "/icons/{pokemon_type}.svg" } }
+                                    b { "{pokemon_type}" }
+                                }
+                            }
+                        }
+                    }
+                    for (entry, is_form) in rows {
+                        DexRow {
+                            faves: faves,
+                            caught,
+                            focus_state,
+                            cursor,
+                            pokemon_type,
+                            exclusive: exclusive(&entry.name),
+                            entry,
+                            is_form,
+                        }
+                    }
+                }
             }
-            tbody {
-                for entry in pokemon() {
-                    DexRow { faves: faves, focus_state, entry: entry.clone() }
+        }
+        if !trade_needed.read().is_empty() {
+            details {
+                summary { "Trade needed ({trade_needed.read().len()})" }
+                ul {
+                    for name in trade_needed() {
+                        li { "{name}" }
+                    }
                 }
             }
         }
     }
 }
 
+/// The rows of one type, keeping their sort order, with whether each is a grouped form.
+fn section(rows: &[Entry], pokemon_type: &str) -> Vec<(Entry, bool)> {
+    let rows = rows
+        .iter()
+        .filter(|e| types(e).iter().any(|t| t == pokemon_type))
+        .cloned()
+        .collect::<Vec<_>>();
+    (0..rows.len())
+        .map(|i| (rows[i].clone(), is_form(&rows, i)))
+        .collect()
+}
+
+/// Reverses sorted rows while keeping each default form ahead of the forms
+/// grouped beneath it.
+fn reverse_groups(rows: Vec<Entry>) -> Vec<Entry> {
+    let mut groups = Vec::<Vec<Entry>>::new();
+    for i in 0..rows.len() {
+        match groups.last_mut() {
+            Some(group) if is_form(&rows, i) => group.push(rows[i].clone()),
+            _ => groups.push(vec![rows[i].clone()]),
+        }
+    }
+    groups.into_iter().rev().flatten().collect()
+}
+
+/// Whether an entry is an alternate form listed under its species' default form,
+/// so it can be grouped beneath it.
+fn is_form(pokemon: &[Entry], i: usize) -> bool {
+    let species = |p: &Entry| p.pokemon_v2_pokemonspecy.as_ref().map(|s| s.name.clone());
+    !pokemon[i].is_default && i > 0 && species(&pokemon[i - 1]) == species(&pokemon[i])
+}
+
 #[component]
 fn DexRow(
-    faves: Signal<HashSet<String>>,
+    faves: Signal<Collections>,
+    caught: Signal<Caught>,
     focus_state: Signal<FocusState>,
-    entry: ReadOnlySignal<dex_by_type::DexByTypePokemonV2Pokemon>,
+    mut cursor: Signal<Cursor>,
+    pokemon_type: &'static str,
+    exclusive: Option<Version>,
+    entry: ReadOnlySignal<Entry>,
+    is_form: bool,
 ) -> Element {
+    let selected = cursor.read().as_ref() == Some(&(pokemon_type, entry.read().name.clone()));
+    let my_game = use_my_game();
+    let unavailable = matches!((exclusive, my_game()), (Some(v), Some(game)) if v != game);
+
     rsx! {
         tr { class: "border-2 hover:bg-gray-100 hover:ring-2 hover:ring-pink-500 hover:ring-inset",
+            id: row_id(pokemon_type, &entry.read().name),
+            border_left: "6px solid {TYPES_INFO.get(pokemon_type).unwrap().color}",
+            background_color: if selected { "#fce7f3" } else { "" },
+            opacity: if unavailable { "0.4" } else { "1" },
+            td { "{dex_number(&entry.read())}" }
+            td {
+                padding_left: if is_form { "20px" } else { "0px" },
+                onclick: move |_| {
+                    cursor.set(Some((pokemon_type, entry().name)));
+                    load_focus(focus_state, entry())
+                },
+                if is_form { "↳ " }
+                "{entry().name}"
+                if let Some(lock) = shiny_lock(&entry.read().name) {
+                    span { title: "{lock.note}", margin_left: "5px",
+                        if lock.obtainable { "🔓" } else { "🔒" }
+                    }
+                }
+                if let Some(version) = exclusive {
+                    VersionBadge { version }
+                }
+            }
+            td { "{types(&entry.read()).join(\"/\")}" }
+            td { "{base_stat_total(&entry.read())}" }
+            td { "{capture_rate(&entry.read())}" }
             td {
                 div { display: "flex", flex_direction: "row",
                     div {
-                        width: "80%",
-                        onclick: move |_| { load_focus(focus_state, entry) },
-                        "{entry().name}"
+                        width: "50%",
+                        onclick: move |_| toggle_caught(caught, &entry().name),
+                        i { class: "fa fa-check-circle", color: if caught.read().contains_key(&entry().name) { "green" } else { "grey" }},
                     }
                     div {
-                        width: "20%",
-                        onclick: move |_|  {
-                            let name = &entry().name;
-                            info!("clicked on {name}");
-                            if faves().contains(&entry().name) {
-                                info!("removing {name} from faves");
-                                faves.write().remove(&entry().name);
-                            } else {
-                                info!("adding {name} to faves");
-                                faves.write().insert(entry().name);
-                            }
-                        },
-                        i { class: "fa fa-heart", color: if faves().contains(&entry().name) { "red" } else { "grey" }},
+                        width: "50%",
+                        onclick: move |_| toggle_favourite(faves, &entry().name),
+                        i { class: "fa fa-heart", color: if is_favourite(&faves.read(), &entry().name) { "red" } else { "grey" }},
                     }
                 }
             }
@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+
+use crate::shiny_dex::focus::gql_query;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct Evolutions;
+
+type Species = evolutions::EvolutionsPokemonV2Pokemonspecies;
+type Evolution = evolutions::EvolutionsPokemonV2PokemonspeciesPokemonV2Pokemonevolutions;
+
+/// A species in the chain, with how it is reached from its pre-evolution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stage {
+    pub pokemon: String,
+    pub depth: usize,
+    pub capture_rate: i64,
+    pub triggers: Vec<String>,
+}
+
+/// Describes an evolution condition, e.g. "Lv. 16" or "trade holding metal-coat".
+fn describe(evolution: &Evolution) -> String {
+    let trigger = evolution
+        .pokemon_v2_evolutiontrigger
+        .as_ref()
+        .map(|t| t.name.as_str())
+        .unwrap_or_default();
+    let mut text = match trigger {
+        "level-up" => match (evolution.min_level, evolution.min_happiness) {
+            (Some(level), _) => format!("Lv. {level}"),
+            (None, Some(_)) => "level up with high friendship".to_string(),
+            (None, None) => "level up".to_string(),
+        },
+        "use-item" => match &evolution.pokemon_v2_item {
+            Some(item) => format!("use {}", item.name),
+            None => "use item".to_string(),
+        },
+        "trade" => "trade".to_string(),
+        other => other.replace('-', " "),
+    };
+    if let Some(held_item) = &evolution.held_item {
+        text.push_str(&format!(" holding {}", held_item.name));
+    }
+    match evolution.time_of_day.as_deref() {
+        Some(time) if !time.is_empty() => text.push_str(&format!(" ({time})")),
+        _ => {}
+    }
+    text
+}
+
+/// Orders the chain into stages, each species after its pre-evolution.
+fn stages(species: &[Species]) -> Vec<Stage> {
+    let depth = |s: &Species| {
+        let mut depth = 0;
+        let mut from = s.evolves_from_species_id;
+        while let Some(parent) = from.and_then(|id| species.iter().find(|s| s.id == id)) {
+            depth += 1;
+            from = parent.evolves_from_species_id;
+        }
+        depth
+    };
+    let mut stages = species
+        .iter()
+        .map(|s| Stage {
+            pokemon: s
+                .pokemon_v2_pokemons
+                .first()
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| s.name.clone()),
+            depth: depth(s),
+            capture_rate: s.capture_rate.unwrap_or_default(),
+            triggers: s
+                .pokemon_v2_pokemonevolutions
+                .iter()
+                .map(describe)
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    stages.sort_by_key(|s| s.depth);
+    stages
+}
+
+/// An earlier stage that is easier to catch than `pokemon`, if there is one.
+fn easier_stage<'a>(stages: &'a [Stage], pokemon: &str) -> Option<&'a Stage> {
+    let current = stages.iter().find(|s| s.pokemon == pokemon)?;
+    stages
+        .iter()
+        .filter(|s| s.depth < current.depth && s.capture_rate > current.capture_rate)
+        .max_by_key(|s| s.capture_rate)
+}
+
+#[component]
+pub fn EvolutionChain(pokemon: ReadOnlySignal<String>, on_select: EventHandler<String>) -> Element {
+    let chain = use_resource(move || async move {
+        let variables = evolutions::Variables { name: pokemon() };
+        gql_query::<Evolutions>(variables)
+            .await
+            .map(|data| stages(&data.pokemon_v2_pokemonspecies))
+    });
+
+    let stages = match &*chain.read_unchecked() {
+        Some(Ok(stages)) => stages.clone(),
+        Some(Err(err)) => return rsx! {"An error occurred while loading the evolution chain {err}"},
+        None => return rsx! {"Loading evolution chain"},
+    };
+    if stages.len() < 2 {
+        return rsx! { p { "{pokemon} does not evolve" } };
+    }
+    let max_depth = stages.iter().map(|s| s.depth).max().unwrap_or_default();
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", align_items: "center",
+            for depth in 0..=max_depth {
+                if depth > 0 {
+                    span { margin: "10px", "→" }
+                }
+                div { display: "flex", flex_direction: "column",
+                    for stage in stages.iter().filter(|s| s.depth == depth).cloned() {
+                        button {
+                            class: "border-2",
+                            margin: "5px",
+                            padding: "5px",
+                            disabled: stage.pokemon == pokemon(),
+                            onclick: move |_| on_select.call(stage.pokemon.clone()),
+                            b { "{stage.pokemon}" }
+                            if !stage.triggers.is_empty() {
+                                br {}
+                                "{stage.triggers.join(\" or \")}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(easier) = easier_stage(&stages, &pokemon()) {
+            p {
+                "Tip: shinies stay shiny when they evolve, and {easier.pokemon} is easier to catch "
+                "(capture rate {easier.capture_rate}). Consider hunting it and evolving."
+            }
+        }
+    }
+}
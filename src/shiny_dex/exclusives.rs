@@ -0,0 +1,204 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+use phf::phf_map;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::shiny_dex::focus::gql_query;
+use crate::shiny_dex::storage::{use_stored, MyGameKey};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct VersionAvailability;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Version {
+    Scarlet,
+    Violet,
+}
+
+impl Version {
+    pub const ALL: [Version; 2] = [Version::Scarlet, Version::Violet];
+
+    /// The version's name in PokeAPI.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Version::Scarlet => "scarlet",
+            Version::Violet => "violet",
+        }
+    }
+
+    pub fn badge(&self) -> &'static str {
+        match self {
+            Version::Scarlet => "S",
+            Version::Violet => "V",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            Version::Scarlet => "#D32F2F",
+            Version::Violet => "#7B1FA2",
+        }
+    }
+}
+
+/// Bundled version exclusives, used over PokeAPI as it has little data for
+/// Scarlet and Violet. Evolutions are listed since they can't be evolved
+/// from a traded-in pre-evolution without trading anyway.
+static EXCLUSIVES: phf::Map<&'static str, Version> = phf_map! {
+    "larvitar" => Version::Scarlet,
+    "pupitar" => Version::Scarlet,
+    "tyranitar" => Version::Scarlet,
+    "drifloon" => Version::Scarlet,
+    "drifblim" => Version::Scarlet,
+    "stunky" => Version::Scarlet,
+    "skuntank" => Version::Scarlet,
+    "deino" => Version::Scarlet,
+    "zweilous" => Version::Scarlet,
+    "hydreigon" => Version::Scarlet,
+    "skrelp" => Version::Scarlet,
+    "dragalge" => Version::Scarlet,
+    "gligar" => Version::Scarlet,
+    "gliscor" => Version::Scarlet,
+    "oranguru" => Version::Scarlet,
+    "stonjourner" => Version::Scarlet,
+    "armarouge" => Version::Scarlet,
+    "great-tusk" => Version::Scarlet,
+    "scream-tail" => Version::Scarlet,
+    "brute-bonnet" => Version::Scarlet,
+    "flutter-mane" => Version::Scarlet,
+    "slither-wing" => Version::Scarlet,
+    "sandy-shocks" => Version::Scarlet,
+    "roaring-moon" => Version::Scarlet,
+    "walking-wake" => Version::Scarlet,
+    "gouging-fire" => Version::Scarlet,
+    "raging-bolt" => Version::Scarlet,
+    "koraidon" => Version::Scarlet,
+    // The Teal Mask
+    "ekans" => Version::Scarlet,
+    "arbok" => Version::Scarlet,
+    "bagon" => Version::Violet,
+    "shelgon" => Version::Violet,
+    "salamence" => Version::Violet,
+    "misdreavus" => Version::Violet,
+    "mismagius" => Version::Violet,
+    "gulpin" => Version::Violet,
+    "swalot" => Version::Violet,
+    "dreepy" => Version::Violet,
+    "drakloak" => Version::Violet,
+    "dragapult" => Version::Violet,
+    "clauncher" => Version::Violet,
+    "clawitzer" => Version::Violet,
+    "passimian" => Version::Violet,
+    "eiscue" => Version::Violet,
+    "ceruledge" => Version::Violet,
+    "iron-treads" => Version::Violet,
+    "iron-bundle" => Version::Violet,
+    "iron-hands" => Version::Violet,
+    "iron-jugulis" => Version::Violet,
+    "iron-moth" => Version::Violet,
+    "iron-thorns" => Version::Violet,
+    "iron-valiant" => Version::Violet,
+    "iron-leaves" => Version::Violet,
+    "iron-boulder" => Version::Violet,
+    "iron-crown" => Version::Violet,
+    "miraidon" => Version::Violet,
+    // The Teal Mask
+    "koffing" => Version::Violet,
+    "weezing" => Version::Violet,
+};
+
+/// The bundled version a pokemon is exclusive to, if any.
+pub fn exclusive_version(pokemon: &str) -> Option<Version> {
+    EXCLUSIVES.get(pokemon).copied()
+}
+
+/// The version a pokemon is exclusive to, if it's only found in one.
+pub type Exclusives = HashMap<String, Version>;
+
+/// Version exclusives for the given pokemon, from the bundled list or, for
+/// pokemon not on it, from which versions PokeAPI has game data for.
+pub async fn load_exclusives(names: Vec<String>) -> Exclusives {
+    let mut exclusives = names
+        .iter()
+        .filter_map(|name| EXCLUSIVES.get(name).map(|v| (name.clone(), *v)))
+        .collect::<Exclusives>();
+
+    let variables = version_availability::Variables {
+        names,
+        versions: Version::ALL.iter().map(|v| v.name().to_string()).collect(),
+    };
+    // PokeAPI data only fills gaps, so a failed request still leaves the bundled list.
+    let Ok(data) = gql_query::<VersionAvailability>(variables).await else {
+        return exclusives;
+    };
+    for pokemon in data.pokemon_v2_pokemon {
+        let seen_in = |version: &Version| {
+            let in_game_index = pokemon
+                .pokemon_v2_pokemongameindices
+                .iter()
+                .filter_map(|g| g.pokemon_v2_version.as_ref())
+                .any(|v| v.name == version.name());
+            let in_encounters = pokemon
+                .pokemon_v2_encounters
+                .iter()
+                .filter_map(|e| e.pokemon_v2_version.as_ref())
+                .any(|v| v.name == version.name());
+            in_game_index || in_encounters
+        };
+        if let [version] = Version::ALL
+            .iter()
+            .filter(|v| seen_in(v))
+            .collect::<Vec<_>>()[..]
+        {
+            exclusives.entry(pokemon.name.clone()).or_insert(*version);
+        }
+    }
+    exclusives
+}
+
+pub fn use_my_game() -> Signal<Option<Version>> {
+    use_stored::<MyGameKey>()
+}
+
+#[component]
+pub fn MyGameSelect() -> Element {
+    let mut my_game = use_my_game();
+
+    rsx! {
+        select {
+            class: "bg-white font-bold py-2 px-4 rounded",
+            oninput: move |event| {
+                my_game.set(Version::ALL.into_iter().find(|v| v.name() == event.data.value()));
+            },
+            option { value: "", selected: my_game().is_none(), "Both games" }
+            for version in Version::ALL {
+                option {
+                    value: version.name(),
+                    selected: my_game() == Some(version),
+                    "Pokémon {version.name()}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn VersionBadge(version: Version) -> Element {
+    rsx! {
+        span {
+            title: "{version.name()} exclusive",
+            margin_left: "5px",
+            padding: "0 4px",
+            border_radius: "4px",
+            color: "white",
+            background_color: version.color(),
+            "{version.badge()}"
+        }
+    }
+}
@@ -1,75 +1,429 @@
 use dioxus::prelude::*;
-use dioxus_sdk::storage::use_persistent;
+use dioxus_logger::tracing::info;
+use graphql_client::GraphQLQuery;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap};
 
 use crate::footer;
-use crate::BASE_REST_API_URL;
+use crate::shiny_dex::focus::{artwork, gql_query};
+use crate::shiny_dex::hunt::HuntMethod;
+use crate::shiny_dex::storage::{use_stored, CollectionsKey};
+
+#[allow(non_camel_case_types)]
+type jsonb = serde_json::Map<String, serde_json::Value>;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct FavouriteSprites;
+
+/// The collection existing favourites were moved into.
+pub const DEFAULT_COLLECTION: &str = "Favourites";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 3] = [Priority::High, Priority::Normal, Priority::Low];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Normal => "Normal",
+            Priority::High => "High",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FavouriteEntry {
+    pub pokemon: String,
+    pub note: String,
+    pub priority: Priority,
+    /// The method the user plans to hunt it with, if they've picked one.
+    pub method: Option<HuntMethod>,
+}
+
+impl FavouriteEntry {
+    pub fn new(pokemon: &str) -> Self {
+        Self {
+            pokemon: pokemon.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A named list of favourites, kept in the order the user arranged it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Collection {
+    pub name: String,
+    pub entries: Vec<FavouriteEntry>,
+}
+
+impl Collection {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn contains(&self, pokemon: &str) -> bool {
+        self.entries.iter().any(|e| e.pokemon == pokemon)
+    }
+}
+
+pub type Collections = Vec<Collection>;
+
+/// Whether the pokemon is in any collection.
+pub fn is_favourite(collections: &Collections, pokemon: &str) -> bool {
+    collections.iter().any(|c| c.contains(pokemon))
+}
+
+/// Every pokemon in any collection, without duplicates.
+pub fn favourite_names(collections: &Collections) -> BTreeSet<String> {
+    collections
+        .iter()
+        .flat_map(|c| c.entries.iter().map(|e| e.pokemon.clone()))
+        .collect()
+}
+
+/// Removes the pokemon from every collection it's in, or otherwise adds it
+/// to the first one.
+pub fn toggle_favourite(mut collections: Signal<Collections>, pokemon: &str) {
+    if is_favourite(&collections.read(), pokemon) {
+        info!("removing {pokemon} from favourites");
+        for collection in collections.write().iter_mut() {
+            collection.entries.retain(|e| e.pokemon != pokemon);
+        }
+        return;
+    }
+    info!("adding {pokemon} to favourites");
+    let mut collections = collections.write();
+    if collections.is_empty() {
+        collections.push(Collection::new(DEFAULT_COLLECTION));
+    }
+    collections[0].entries.push(FavouriteEntry::new(pokemon));
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Sprites {
+    default: Option<String>,
+    shiny: Option<String>,
+}
+
+/// Artwork for every name PokeAPI knows, in one request. Unknown names are
+/// left out rather than failing the lot.
+async fn load_sprites(names: BTreeSet<String>) -> Result<HashMap<String, Sprites>, String> {
+    let variables = favourite_sprites::Variables {
+        names: names.into_iter().collect(),
+    };
+    let data = gql_query::<FavouriteSprites>(variables)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(data
+        .pokemon_v2_pokemon
+        .into_iter()
+        .map(|pokemon| {
+            let sprites = pokemon
+                .pokemon_v2_pokemonsprites
+                .first()
+                .map(|s| &s.sprites);
+            let sprites = Sprites {
+                default: sprites.and_then(|s| artwork(s, "front_default")),
+                shiny: sprites.and_then(|s| artwork(s, "front_shiny")),
+            };
+            (pokemon.name, sprites)
+        })
+        .collect())
+}
 
 #[component]
 pub fn Favourites() -> Element {
-    let faves = use_persistent("faves", || HashSet::<String>::new());
+    let mut collections = use_stored::<CollectionsKey>();
+    let mut selected = use_signal(|| 0);
+    let mut new_name = use_signal(String::new);
+    let mut shiny = use_signal(|| true);
+
+    // Only refetch when the set of names changes, not on every note edit.
+    let names = use_memo(move || favourite_names(&collections.read()));
+    let sprites = use_resource(move || load_sprites(names()));
+
+    let mut add_collection = move || {
+        let name = new_name().trim().to_string();
+        if name.is_empty() || collections.read().iter().any(|c| c.name == name) {
+            return;
+        }
+        collections.write().push(Collection::new(&name));
+        selected.set(collections.read().len() - 1);
+        new_name.set(String::new());
+    };
+    let index = selected().min(collections.read().len().saturating_sub(1));
 
     rsx! {
         h1 { "Favourites" }
-        for fav in faves() {
-            FavouritePokemon { pokemon_name: fav }
+        div { display: "flex", flex_direction: "row", align_items: "center",
+            for (i, collection) in collections().into_iter().enumerate() {
+                button {
+                    class: if i == index { "bg-blue-500 text-white font-bold py-2 px-4 rounded" } else { "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded" },
+                    margin: "5px",
+                    onclick: move |_| selected.set(i),
+                    "{collection.name} ({collection.entries.len()})"
+                }
+            }
+            input {
+                r#type: "text",
+                class: "border-2",
+                margin: "5px",
+                placeholder: "New collection",
+                value: "{new_name}",
+                oninput: move |e| new_name.set(e.data.value()),
+                onkeydown: move |e| {
+                    if e.key() == Key::Enter {
+                        add_collection();
+                    }
+                }
+            }
+            button {
+                class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                onclick: move |_| add_collection(),
+                "Add"
+            }
+            label { margin_left: "20px",
+                input {
+                    r#type: "checkbox",
+                    checked: shiny(),
+                    oninput: move |_| shiny.set(!shiny())
+                }
+                " Show shiny"
+            }
+        }
+        match &*sprites.read_unchecked() {
+            Some(Err(err)) => rsx! { p { "Couldn't load sprites: {err}" } },
+            None => rsx! { p { "Loading sprites" } },
+            _ => rsx! {},
+        }
+        if !collections.read().is_empty() {
+            CollectionView {
+                collections,
+                index,
+                sprites: sprites.read().as_ref().and_then(|s| s.as_ref().ok()).cloned(),
+                shiny: shiny(),
+            }
         }
         footer::Footer {}
     }
 }
 
 #[component]
-fn FavouritePokemon(pokemon_name: ReadOnlySignal<String>) -> Element {
-    let pokemon_res =
-        use_resource(move || async move { get_pokemon(pokemon_name()).await.unwrap() });
+fn CollectionView(
+    collections: Signal<Collections>,
+    index: usize,
+    /// None while loading, or if loading failed.
+    sprites: Option<HashMap<String, Sprites>>,
+    shiny: bool,
+) -> Element {
+    let mut dragging = use_signal(|| None::<usize>);
+    let collection = collections.read()[index].clone();
+
+    let mut move_entry = move |from: usize, to: usize| {
+        let mut collections = collections.write();
+        let entries = &mut collections[index].entries;
+        if from < entries.len() && to < entries.len() {
+            let entry = entries.remove(from);
+            entries.insert(to, entry);
+        }
+    };
 
-    match &*pokemon_res.read_unchecked() {
-        Some(pokemon) => rsx! { RenderPokemon { pokemon: pokemon.clone() } },
-        _ => rsx! {"Loading items"},
+    rsx! {
+        div { display: "flex", flex_direction: "row", align_items: "center",
+            "Name: "
+            input {
+                r#type: "text",
+                class: "border-2",
+                margin: "5px",
+                value: "{collection.name}",
+                oninput: move |e| collections.write()[index].name = e.data.value()
+            }
+            // Keep at least one collection for the dex's heart button to add to.
+            if collections.read().len() > 1 {
+                button {
+                    class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                    onclick: move |_| {
+                        collections.write().remove(index);
+                    },
+                    "Delete collection"
+                }
+            }
+        }
+        if collection.entries.is_empty() {
+            p { "Nothing here yet, add pokemon with the heart in the shiny dex." }
+        } else {
+            p { "Drag entries to reorder them." }
+        }
+        div {
+            display: "grid",
+            grid_template_columns: "repeat(auto-fill, minmax(220px, 1fr))",
+            gap: "10px",
+            for (i, entry) in collection.entries.into_iter().enumerate() {
+                div {
+                    key: "{entry.pokemon}",
+                    class: "border-2 rounded",
+                    padding: "5px",
+                    draggable: "true",
+                    opacity: if dragging() == Some(i) { "0.5" } else { "1" },
+                    ondragstart: move |_| dragging.set(Some(i)),
+                    ondragend: move |_| dragging.set(None),
+                    ondragover: move |e| e.prevent_default(),
+                    ondrop: move |e| {
+                        e.prevent_default();
+                        if let Some(from) = dragging.take() {
+                            move_entry(from, i);
+                        }
+                    },
+                    FavouriteSprite {
+                        pokemon: entry.pokemon.clone(),
+                        sprites: sprites.as_ref().map(|s| s.get(&entry.pokemon).cloned()),
+                        shiny,
+                    }
+                    FavouriteEntryEditor { collections, index, position: i, entry }
+                }
+            }
+        }
     }
 }
 
+/// `sprites` is None while loading, and Some(None) for names PokeAPI doesn't know.
 #[component]
-fn RenderPokemon(pokemon: Pokemon) -> Element {
-    let pokemon_name = pokemon.name.clone();
-    let default_image = pokemon.sprites.other.official_artwork.front_default.clone();
-    let shiny_image = pokemon.sprites.other.official_artwork.front_shiny.clone();
+fn FavouriteSprite(pokemon: String, sprites: Option<Option<Sprites>>, shiny: bool) -> Element {
+    let url = sprites.clone().flatten().and_then(|s| {
+        if shiny {
+            s.shiny.or(s.default)
+        } else {
+            s.default
+        }
+    });
 
     rsx! {
-        h1 { "{pokemon_name}" }
-        div { display: "flex", flex_direction: "row",
-            img { src: "{default_image}", width: "100%" }
-            img { src: "{shiny_image.clone().unwrap_or_default()}", width: "100%" }
+        div {
+            height: "160px",
+            display: "flex",
+            align_items: "center",
+            justify_content: "center",
+            match (sprites, url) {
+                (_, Some(url)) => rsx! { img { src: "{url}", alt: "{pokemon}", max_height: "160px" } },
+                (Some(None), _) => rsx! { span { color: "grey", "Unknown pokemon" } },
+                (Some(Some(_)), None) => rsx! { span { color: "grey", "No artwork" } },
+                (None, None) => rsx! { span { color: "grey", "…" } },
+            }
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Pokemon {
-    name: String,
-    sprites: Sprite,
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Sprite {
-    other: Other,
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Other {
-    #[serde(rename = "official-artwork")]
-    official_artwork: OfficialArtwork,
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct OfficialArtwork {
-    front_default: String,
-    front_shiny: Option<String>,
-}
+#[component]
+fn FavouriteEntryEditor(
+    collections: Signal<Collections>,
+    index: usize,
+    position: usize,
+    entry: FavouriteEntry,
+) -> Element {
+    let mut edit = move |f: &dyn Fn(&mut FavouriteEntry)| {
+        let mut collections = collections.write();
+        if let Some(entry) = collections
+            .get_mut(index)
+            .and_then(|collection| collection.entries.get_mut(position))
+        {
+            f(entry);
+        }
+    };
+    let others = collections
+        .read()
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| *i != index && !c.contains(&entry.pokemon))
+        .map(|(i, c)| (i, c.name.clone()))
+        .collect::<Vec<_>>();
 
-async fn get_pokemon(name: String) -> Result<Pokemon, reqwest::Error> {
-    log::info!("Fetching pokemon {}", name);
-    let url = format!("{}/pokemon/{}", BASE_REST_API_URL, name);
-    reqwest::get(&url).await?.json().await
+    rsx! {
+        div { display: "flex", flex_direction: "row", align_items: "center",
+            span { cursor: "grab", margin_right: "10px", "☰" }
+            b { "{entry.pokemon}" }
+        }
+        div { display: "flex", flex_direction: "column",
+            label {
+                "Priority: "
+                select {
+                    oninput: move |e| {
+                        let priority = Priority::ALL.into_iter().find(|p| p.label() == e.data.value());
+                        edit(&|entry| entry.priority = priority.unwrap_or_default());
+                    },
+                    for priority in Priority::ALL {
+                        option { value: priority.label(), selected: entry.priority == priority, "{priority.label()}" }
+                    }
+                }
+            }
+            label {
+                "Method: "
+                select {
+                    oninput: move |e| {
+                        let method = HuntMethod::ALL.into_iter().find(|m| m.label() == e.data.value());
+                        edit(&|entry| entry.method = method);
+                    },
+                    option { value: "", selected: entry.method.is_none(), "Undecided" }
+                    for method in HuntMethod::ALL {
+                        option { value: method.label(), selected: entry.method == Some(method), "{method.label()}" }
+                    }
+                }
+            }
+            textarea {
+                class: "border-2",
+                placeholder: "Notes",
+                value: "{entry.note}",
+                oninput: move |e| {
+                    let note = e.data.value();
+                    edit(&|entry| entry.note = note.clone());
+                }
+            }
+            div { display: "flex", flex_direction: "row",
+                if !others.is_empty() {
+                    select {
+                        oninput: move |e| {
+                            let Ok(to) = e.data.value().parse::<usize>() else {
+                                return;
+                            };
+                            let mut collections = collections.write();
+                            if position < collections[index].entries.len() {
+                                let entry = collections[index].entries.remove(position);
+                                collections[to].entries.push(entry);
+                            }
+                        },
+                        option { value: "", selected: true, "Move to…" }
+                        for (i, name) in others {
+                            option { value: "{i}", "{name}" }
+                        }
+                    }
+                }
+                button {
+                    class: "bg-gray-300 hover:bg-gray-400 font-bold py-1 px-2 rounded",
+                    margin_left: "auto",
+                    onclick: move |_| {
+                        if let Some(collection) = collections.write().get_mut(index) {
+                            if position < collection.entries.len() {
+                                collection.entries.remove(position);
+                            }
+                        }
+                    },
+                    "Remove"
+                }
+            }
+        }
+    }
 }
@@ -1,12 +1,25 @@
-use charming::component::{RadarCoordinate, Title};
-use charming::series::Radar;
+use charming::component::{Axis, RadarCoordinate, Title};
+use charming::element::{
+    AxisType, Label, MarkLine, MarkLineData, MarkLineVariant, MarkPoint, MarkPointData, Tooltip,
+    Trigger,
+};
+use charming::series::{Line, Radar};
 use charming::{Chart, WasmRenderer};
 use dioxus::prelude::*;
 use graphql_client::{GraphQLQuery, Response};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
-use crate::shiny_dex::TYPES_INFO;
+use crate::shiny_dex::caught::{Caught, OwnedPanel};
+use crate::shiny_dex::evolution::EvolutionChain;
+use crate::shiny_dex::hunt::{HuntPanel, Hunts};
+use crate::shiny_dex::inventory::{can_make, consume, MakeableRecipes};
+use crate::shiny_dex::locations::Locations;
+use crate::shiny_dex::meal_timer::{eat, use_meal_timer};
+use crate::shiny_dex::recipe_finder::RecipeFinder;
+use crate::shiny_dex::sandwich::{Sandwich, SandwichBuilder, SandwichResult};
+use crate::shiny_dex::shiny_lock::{shiny_lock, SHINY_LOCKS_VERSION};
+use crate::shiny_dex::storage::{use_stored, InventoryKey};
 use crate::BASE_GRAPHQL_API_URL;
 
 #[derive(Clone)]
@@ -27,28 +40,54 @@ pub struct FocusData {
     capture_rate: i64,
 }
 
+impl FocusData {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[component]
-pub fn Focus(focus_state: ReadOnlySignal<FocusState>) -> Element {
+pub fn Focus(
+    focus_state: Signal<FocusState>,
+    caught: Signal<Caught>,
+    hunts: Signal<Hunts>,
+) -> Element {
     match &*focus_state.read() {
         FocusState::Unset => rsx! {"Click on a pokemon to preview it here"},
         FocusState::Loading => rsx! {"Loading..."},
         FocusState::Loaded(focus_data) => {
-            rsx! { FocusDetail { focus_data: focus_data.clone() } }
+            rsx! { FocusDetail { focus_data: focus_data.clone(), focus_state, caught, hunts } }
         }
         FocusState::Failed(err) => rsx! {"{err}"},
     }
 }
 
 #[component]
-fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
-    let mut chain = use_signal(|| 0);
-    let mut sandwich = use_signal(|| 0);
-    let mut shiny_charm = use_signal(|| false);
-    let (odds, rolls) = shiny_odds(
-        chain.read().clone(),
-        sandwich.read().clone(),
-        shiny_charm.read().clone(),
-    );
+fn FocusDetail(
+    focus_data: ReadOnlySignal<FocusData>,
+    focus_state: Signal<FocusState>,
+    caught: Signal<Caught>,
+    mut hunts: Signal<Hunts>,
+) -> Element {
+    let mut inventory = use_stored::<InventoryKey>();
+    let pokemon_name = use_memo(move || focus_data.read().name.clone());
+    let (odds, rolls) = hunt_odds(hunts, &pokemon_name());
+    let custom_sandwich = use_signal(Sandwich::default);
+
+    let meal_timer = use_meal_timer();
+
+    // Eating a sandwich applies it to the current hunt and starts the meal timer.
+    let mut ate_sandwich = move |sandwich: Sandwich| {
+        if let Some(hunt) = hunts.write().get_mut(&pokemon_name()) {
+            hunt.sandwich_level = sandwich.sparkling_level();
+        }
+        eat(meal_timer, sandwich);
+    };
+    // Logging one also uses up its ingredients.
+    let mut log_sandwich = move |sandwich: Sandwich| {
+        consume(&mut inventory.write(), &sandwich);
+        ate_sandwich(sandwich);
+    };
 
     let serebii_link = format!(
         "https://www.serebii.net/pokedex-sv/{}",
@@ -76,6 +115,16 @@ fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
         renderer.read_unchecked().render("chart", &chart).unwrap();
     });
 
+    let odds_renderer = use_signal(|| WasmRenderer::new(600, 320));
+
+    use_effect(move || {
+        let (odds, _) = hunt_odds(hunts, &pokemon_name());
+        odds_renderer
+            .read_unchecked()
+            .render("odds_chart", &odds_chart(odds))
+            .unwrap();
+    });
+
     rsx! {
         div { display: "flex", flex_direction: "row",
             div { margin: "10px", width: "50%",
@@ -86,8 +135,56 @@ fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
                     }
                 }
                 b { "Easy 3 star sparkling/encounter/title sandwich:" }
-                p {
-                    "tomato + onion + green pepper + hamburger + 2 * ({focus_data.read().types.iter().map(|t| TYPES_INFO.get(t.as_str()).unwrap().ingredient).collect::<Vec<&str>>().join(\" or \")})"
+                for t in focus_data.read().types.clone() {
+                    p { "{Sandwich::sparkling(&t)}" }
+                    SandwichResult { sandwich: Sandwich::sparkling(&t) }
+                    button {
+                        title: "Start the meal timer without touching your ingredients",
+                        onclick: move |_| ate_sandwich(Sandwich::sparkling(&t)),
+                        "Ate a sandwich"
+                    }
+                }
+                details {
+                    summary { "Sandwiches you can make" }
+                    MakeableRecipes {
+                        pokemon_types: focus_data.read().types.clone(),
+                        inventory,
+                        on_log: log_sandwich
+                    }
+                }
+                details {
+                    summary { "Build your own sandwich" }
+                    SandwichBuilder { sandwich: custom_sandwich }
+                    button {
+                        disabled: !custom_sandwich.read().is_valid() || !can_make(&inventory.read(), &custom_sandwich.read()),
+                        onclick: move |_| log_sandwich(custom_sandwich()),
+                        "Log sandwich"
+                    }
+                    button {
+                        margin_left: "10px",
+                        disabled: !custom_sandwich.read().is_valid(),
+                        title: "Start the meal timer without touching your ingredients",
+                        onclick: move |_| ate_sandwich(custom_sandwich()),
+                        "Ate a sandwich"
+                    }
+                }
+                details {
+                    summary { "Find a recipe" }
+                    RecipeFinder {
+                        pokemon_type: focus_data.read().types.first().cloned().unwrap_or_default(),
+                        inventory
+                    }
+                }
+                details {
+                    summary { "Evolution chain" }
+                    EvolutionChain {
+                        pokemon: pokemon_name,
+                        on_select: move |name| load_focus_by_name(focus_state, name)
+                    }
+                }
+                details {
+                    summary { "Where to find it" }
+                    Locations { pokemon: pokemon_name }
                 }
                 p {
                     a { href: "{serebii_link}", target: "_blank", "Serebii" }
@@ -99,43 +196,12 @@ fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
               style: "display: inline-block; height: 400px; width: 600px;",
             }
             div { margin: "10px", width: "50%",
-                p {
-                    "Shiny Charm: ",
-                    input {
-                        r#type: "checkbox",
-                        oninput: move |_| {
-                            let set = shiny_charm.read().clone();
-                            shiny_charm.set(!set);
-                        },
-                        checked: *shiny_charm.read()
-                    }
-                }
-                p {
-                    "Sandwich Level: ",
-                    select {
-                        oninput: move |e| {
-                            sandwich.set(e.data.value().parse::<i64>().unwrap_or_default());
-                        },
-                        for i in [0, 1, 3] {
-                            option { value: "{i}", b { "{i}" } }
-                        }
-                    }
-                }
-                p {
-                    button {
-                        style: "margin-right: 10px;",
-                        onclick: move |_| {
-                            chain += 1;
-                        },
-                        "Chain +"
-                    }
-                    b {"{chain}" },
-                    button {
-                        style: "margin-left: 10px;",
-                        onclick: move |_| {
-                            chain *= 0;
-                        },
-                        "Chain Reset"
+                OwnedPanel { pokemon: pokemon_name, caught }
+                HuntPanel { pokemon: pokemon_name, hunts }
+                if let Some(lock) = shiny_lock(&pokemon_name()) {
+                    p { color: if lock.obtainable { "darkorange" } else { "red" },
+                        if lock.obtainable { "🔓 " } else { "🔒 Shiny locked, these odds don't apply. " }
+                        "{lock.note} (data for {SHINY_LOCKS_VERSION})"
                     }
                 }
                 p {
@@ -145,8 +211,20 @@ fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
                     b { "{rolls}" }
                     " of 4096"
                 }
+                p {
+                    "Expected encounters: "
+                    b { "{expected_encounters(odds)}" }
+                    for milestone in ODDS_MILESTONES {
+                        " | {milestone * 100.0}%: "
+                        b { "{encounters_for_probability(odds, milestone)}" }
+                    }
+                }
             }
         }
+        div {
+            id: "odds_chart",
+            style: "display: inline-block; height: 320px; width: 600px;",
+        }
         div { display: "flex", flex_direction: "row",
             img { src: "{focus_data.read().default_url}", width: "100%" }
             img {
@@ -157,7 +235,17 @@ fn FocusDetail(focus_data: ReadOnlySignal<FocusData>) -> Element {
     }
 }
 
-fn shiny_odds(chain: i64, sandwich_level: i64, shiny_charm: bool) -> (f64, i64) {
+/// Odds for the focused pokemon's hunt, or base odds if it isn't being hunted.
+fn hunt_odds(hunts: Signal<Hunts>, pokemon: &str) -> (f64, i64) {
+    hunts
+        .read()
+        .get(pokemon)
+        .cloned()
+        .unwrap_or_default()
+        .odds()
+}
+
+pub fn shiny_odds(chain: i64, sandwich_level: i64, shiny_charm: bool) -> (f64, i64) {
     let mut rolls = sandwich_level + 1;
     match chain {
         30..=59 => rolls += 1,
@@ -167,22 +255,109 @@ fn shiny_odds(chain: i64, sandwich_level: i64, shiny_charm: bool) -> (f64, i64)
     if shiny_charm {
         rolls += 2;
     };
-    let odds = 1.0 - (4095.0 / 4096.0f64).powi(rolls as i32);
-    (odds, rolls)
+    (odds_from_rolls(rolls), rolls)
+}
+
+pub fn odds_from_rolls(rolls: i64) -> f64 {
+    1.0 - (4095.0 / 4096.0f64).powi(rolls as i32)
+}
+
+/// The cumulative probabilities marked on the odds chart.
+const ODDS_MILESTONES: [f64; 3] = [0.5, 0.9, 0.99];
+
+/// Probability of having found at least one shiny after `encounters` encounters.
+fn cumulative_probability(odds: f64, encounters: i64) -> f64 {
+    1.0 - (1.0 - odds).powi(encounters as i32)
+}
+
+/// Number of encounters needed before the cumulative probability reaches `probability`.
+fn encounters_for_probability(odds: f64, probability: f64) -> i64 {
+    ((1.0 - probability).ln() / (1.0 - odds).ln()).ceil() as i64
+}
+
+/// Mean number of encounters until the first shiny.
+pub fn expected_encounters(odds: f64) -> i64 {
+    (1.0 / odds).round() as i64
+}
+
+fn odds_chart(odds: f64) -> Chart {
+    let last = encounters_for_probability(odds, ODDS_MILESTONES[ODDS_MILESTONES.len() - 1]);
+    let step = (last / 100).max(1);
+    let data = (0..=last)
+        .step_by(step as usize)
+        .map(|n| vec![n as f64, cumulative_probability(odds, n) * 100.0])
+        .collect::<Vec<_>>();
+
+    let expected = expected_encounters(odds);
+    let mut milestones = ODDS_MILESTONES
+        .iter()
+        .map(|p| {
+            let n = encounters_for_probability(odds, *p);
+            MarkPointData::new()
+                .name(format!("{}%", p * 100.0))
+                .x_axis(n as f64)
+                .y_axis(cumulative_probability(odds, n) * 100.0)
+                .value(n as f64)
+        })
+        .collect::<Vec<_>>();
+    milestones.push(
+        MarkPointData::new()
+            .name("Expected")
+            .x_axis(expected as f64)
+            .y_axis(cumulative_probability(odds, expected) * 100.0)
+            .value(expected as f64),
+    );
+
+    Chart::new()
+        .title(Title::new().text("Chance of at least one shiny"))
+        .tooltip(Tooltip::new().trigger(Trigger::Axis))
+        .x_axis(Axis::new().type_(AxisType::Value).name("Encounters"))
+        .y_axis(Axis::new().type_(AxisType::Value).min(0).max(100).name("%"))
+        .series(
+            Line::new()
+                .name("Cumulative probability")
+                .show_symbol(false)
+                .data(data)
+                .mark_point(MarkPoint::new().data(milestones))
+                .mark_line(
+                    MarkLine::new()
+                        .label(Label::new().formatter("{b}"))
+                        .data(vec![MarkLineVariant::Simple(
+                            MarkLineData::new().name("Expected").x_axis(expected as f64),
+                        )]),
+                ),
+        )
 }
 
 pub async fn load_focus(
     mut focus_state: Signal<FocusState>,
-    pokemon: ReadOnlySignal<dex_by_type::DexByTypePokemonV2Pokemon>,
+    pokemon: dex_by_type::DexByTypePokemonV2Pokemon,
 ) {
     *focus_state.write() = FocusState::Loading;
-    if let Ok(focus_data) = get_data(pokemon()).await {
+    if let Ok(focus_data) = get_data(pokemon).await {
         *focus_state.write() = FocusState::Loaded(focus_data.clone());
     } else {
         *focus_state.write() = FocusState::Failed("Failed to load data".to_string());
     }
 }
 
+/// Focuses a pokemon by name, e.g. when following its evolution chain.
+pub async fn load_focus_by_name(mut focus_state: Signal<FocusState>, name: String) {
+    *focus_state.write() = FocusState::Loading;
+    let pokemon = gql_query::<PokemonByName>(pokemon_by_name::Variables { name })
+        .await
+        .ok()
+        .and_then(|data| data.pokemon_v2_pokemon.into_iter().next())
+        .map(dex_by_type::DexByTypePokemonV2Pokemon::from);
+    match pokemon {
+        Some(pokemon) => match get_data(pokemon).await {
+            Ok(focus_data) => *focus_state.write() = FocusState::Loaded(focus_data),
+            Err(_) => *focus_state.write() = FocusState::Failed("Failed to load data".to_string()),
+        },
+        None => *focus_state.write() = FocusState::Failed("Failed to load data".to_string()),
+    }
+}
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graph/schema.graphql",
@@ -191,27 +366,110 @@ pub async fn load_focus(
 )]
 pub struct DexByType;
 
+/// Same selection as `DexByType`, for focusing a pokemon outside the dex table.
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct PokemonByName;
+
 #[allow(non_camel_case_types)]
 type jsonb = serde_json::Map<String, serde_json::Value>;
 
+/// Reads a `PokemonByName` result as a dex table row. Spelled out field by
+/// field so the two selections can't drift apart without a compile error.
+impl From<pokemon_by_name::PokemonByNamePokemonV2Pokemon>
+    for dex_by_type::DexByTypePokemonV2Pokemon
+{
+    fn from(pokemon: pokemon_by_name::PokemonByNamePokemonV2Pokemon) -> Self {
+        use dex_by_type::*;
+        Self {
+            name: pokemon.name,
+            order: pokemon.order,
+            is_default: pokemon.is_default,
+            pokemon_v2_pokemonspecy: pokemon.pokemon_v2_pokemonspecy.map(|species| {
+                DexByTypePokemonV2PokemonPokemonV2Pokemonspecy {
+                    name: species.name,
+                    capture_rate: species.capture_rate,
+                    pokemon_v2_pokemondexnumbers: species
+                        .pokemon_v2_pokemondexnumbers
+                        .into_iter()
+                        .map(|number| {
+                            DexByTypePokemonV2PokemonPokemonV2PokemonspecyPokemonV2Pokemondexnumbers {
+                                pokedex_number: number.pokedex_number,
+                            }
+                        })
+                        .collect(),
+                }
+            }),
+            pokemon_v2_pokemonstats: pokemon
+                .pokemon_v2_pokemonstats
+                .into_iter()
+                .map(|stat| DexByTypePokemonV2PokemonPokemonV2Pokemonstats {
+                    base_stat: stat.base_stat,
+                    pokemon_v2_stat: stat.pokemon_v2_stat.map(|s| {
+                        DexByTypePokemonV2PokemonPokemonV2PokemonstatsPokemonV2Stat { name: s.name }
+                    }),
+                })
+                .collect(),
+            pokemon_v2_pokemonsprites: pokemon
+                .pokemon_v2_pokemonsprites
+                .into_iter()
+                .map(|sprites| DexByTypePokemonV2PokemonPokemonV2Pokemonsprites {
+                    sprites: sprites.sprites,
+                })
+                .collect(),
+            pokemon_v2_pokemontypes: pokemon
+                .pokemon_v2_pokemontypes
+                .into_iter()
+                .map(|t| DexByTypePokemonV2PokemonPokemonV2Pokemontypes {
+                    pokemon_v2_type: t.pokemon_v2_type.map(|t| {
+                        DexByTypePokemonV2PokemonPokemonV2PokemontypesPokemonV2Type { name: t.name }
+                    }),
+                })
+                .collect(),
+        }
+    }
+}
+
 pub async fn perform_gql_query(
     variables: dex_by_type::Variables,
 ) -> Result<Vec<dex_by_type::DexByTypePokemonV2Pokemon>, Box<dyn Error>> {
-    let request_body = DexByType::build_query(variables);
+    Ok(gql_query::<DexByType>(variables).await?.pokemon_v2_pokemon)
+}
+
+/// Runs any query from `graph/query.graphql` against the PokeAPI GraphQL endpoint.
+pub async fn gql_query<Q: GraphQLQuery>(
+    variables: Q::Variables,
+) -> Result<Q::ResponseData, Box<dyn Error>> {
+    let request_body = Q::build_query(variables);
 
     let gql_addr = BASE_GRAPHQL_API_URL;
 
     let client = reqwest::Client::new();
-    let resp: Response<dex_by_type::ResponseData> = client
-        .post(format!("{gql_addr}"))
+    let resp: Response<Q::ResponseData> = client
+        .post(gql_addr)
         .json(&request_body)
         .send()
-        .await
-        .expect("failed to send request")
+        .await?
         .json()
         .await?;
 
-    Ok(resp.data.ok_or("missing response data")?.pokemon_v2_pokemon)
+    Ok(resp.data.ok_or("missing response data")?)
+}
+
+/// The official artwork for `key`, e.g. "front_shiny". Some newer forms have
+/// none, so this falls back to their game sprites.
+pub fn artwork(sprites: &jsonb, key: &str) -> Option<String> {
+    sprites
+        .get("other")
+        .and_then(|o| o.get("official-artwork"))
+        .and_then(|a| a.get(key))
+        .and_then(|s| s.as_str())
+        .or_else(|| sprites.get(key).and_then(|s| s.as_str()))
+        .map(|s| s.to_string())
 }
 
 async fn get_data(
@@ -224,12 +482,8 @@ async fn get_data(
         .capture_rate
         .unwrap_or_default();
 
-    let sprites = &pokemon.pokemon_v2_pokemonsprites[0]
-        .sprites
-        .get("other")
-        .unwrap()
-        .get("official-artwork")
-        .unwrap();
+    let sprites = &pokemon.pokemon_v2_pokemonsprites[0].sprites;
+    let sprite = |key: &str| artwork(sprites, key);
 
     let types = pokemon
         .pokemon_v2_pokemontypes
@@ -244,15 +498,8 @@ async fn get_data(
             .iter()
             .map(|s| s.base_stat)
             .collect(),
-        default_url: sprites
-            .get("front_default")
-            .unwrap()
-            .to_string()
-            .trim_matches('\"')
-            .to_string(),
-        shiny_url: sprites
-            .get("front_shiny")
-            .map(|s| s.to_string().trim_matches('\"').to_string()),
+        default_url: sprite("front_default").unwrap_or_default(),
+        shiny_url: sprite("front_shiny"),
         types,
         capture_rate,
     })
@@ -0,0 +1,361 @@
+use dioxus::prelude::*;
+use dioxus_sdk::utils::timing::use_interval;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::shiny_dex::focus::{odds_from_rolls, shiny_odds};
+use crate::time::{format_duration, format_timestamp, now};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum HuntMethod {
+    #[default]
+    Wild,
+    Outbreak,
+    Masuda,
+}
+
+impl HuntMethod {
+    pub const ALL: [HuntMethod; 3] = [HuntMethod::Wild, HuntMethod::Outbreak, HuntMethod::Masuda];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HuntMethod::Wild => "Wild encounters",
+            HuntMethod::Outbreak => "Mass outbreak",
+            HuntMethod::Masuda => "Masuda method",
+        }
+    }
+}
+
+/// An off-target shiny found while hunting.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Phase {
+    pub pokemon: String,
+    pub encounters: i64,
+    pub found_at: f64,
+}
+
+/// A stretch of continuous hunting. `ended_at` is bumped on every encounter
+/// while the session is open, so a session left open by closing the tab
+/// ends at the last encounter rather than when the page is next opened.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub started_at: f64,
+    pub ended_at: f64,
+    pub encounters: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Hunt {
+    pub encounters: i64,
+    pub method: HuntMethod,
+    pub sandwich_level: i64,
+    pub shiny_charm: bool,
+    pub started_at: f64,
+    pub completed_at: Option<f64>,
+    pub phases: Vec<Phase>,
+    pub sessions: Vec<Session>,
+    pub session_open: bool,
+}
+
+impl Hunt {
+    pub fn new(started_at: f64) -> Self {
+        Self {
+            started_at,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the odds of a single encounter being shiny and the number of rolls.
+    pub fn odds(&self) -> (f64, i64) {
+        match self.method {
+            HuntMethod::Wild => shiny_odds(0, self.sandwich_level, self.shiny_charm),
+            HuntMethod::Outbreak => {
+                shiny_odds(self.encounters, self.sandwich_level, self.shiny_charm)
+            }
+            // Sandwiches don't affect eggs, the Masuda method adds 5 rolls instead.
+            HuntMethod::Masuda => {
+                let rolls = 6 + if self.shiny_charm { 2 } else { 0 };
+                (odds_from_rolls(rolls), rolls)
+            }
+        }
+    }
+
+    /// Counts an encounter, unless the shiny has already been found.
+    pub fn encounter(&mut self, now: f64) {
+        if self.completed_at.is_some() {
+            return;
+        }
+        self.encounters += 1;
+        if !self.session_open {
+            self.resume(now);
+        }
+        if let Some(session) = self.sessions.last_mut() {
+            session.encounters += 1;
+            session.ended_at = now;
+        }
+    }
+
+    pub fn undo_encounter(&mut self) {
+        if self.encounters == 0 {
+            return;
+        }
+        self.encounters -= 1;
+        if let Some(session) = self.sessions.last_mut() {
+            session.encounters = (session.encounters - 1).max(0);
+        }
+    }
+
+    pub fn resume(&mut self, now: f64) {
+        if self.session_open {
+            return;
+        }
+        self.sessions.push(Session {
+            started_at: now,
+            ended_at: now,
+            encounters: 0,
+        });
+        self.session_open = true;
+    }
+
+    pub fn pause(&mut self, now: f64) {
+        if !self.session_open {
+            return;
+        }
+        if let Some(session) = self.sessions.last_mut() {
+            session.ended_at = now;
+        }
+        self.session_open = false;
+    }
+
+    pub fn phase(&mut self, pokemon: String, now: f64) {
+        self.phases.push(Phase {
+            pokemon,
+            encounters: self.encounters,
+            found_at: now,
+        });
+    }
+
+    pub fn complete(&mut self, now: f64) {
+        self.pause(now);
+        self.completed_at = Some(now);
+    }
+
+    /// Total time spent hunting across all sessions.
+    pub fn elapsed(&self, now: f64) -> f64 {
+        let closed: f64 = self
+            .sessions
+            .iter()
+            .map(|s| s.ended_at - s.started_at)
+            .sum();
+        match (self.session_open, self.sessions.last()) {
+            (true, Some(session)) => closed + (now - session.ended_at),
+            _ => closed,
+        }
+    }
+
+    /// When the hunt last changed, as far as its timestamps tell.
+    pub fn last_activity(&self) -> f64 {
+        let sessions = self.sessions.iter().map(|s| s.ended_at);
+        let phases = self.phases.iter().map(|p| p.found_at);
+        sessions
+            .chain(phases)
+            .chain(self.completed_at)
+            .fold(self.started_at, f64::max)
+    }
+}
+
+pub type Hunts = HashMap<String, Hunt>;
+
+#[component]
+pub fn HuntPanel(pokemon: ReadOnlySignal<String>, hunts: Signal<Hunts>) -> Element {
+    let mut clock = use_signal(now);
+    use_interval(Duration::from_secs(1), move || clock.set(now()));
+
+    // A session still open from a previous page load ends at its last encounter.
+    use_effect(move || {
+        if let Some(hunt) = hunts.write().get_mut(&*pokemon.peek()) {
+            hunt.session_open = false;
+        }
+    });
+
+    let mut phase_name = use_signal(String::new);
+
+    let Some(hunt) = hunts.read().get(&pokemon()).cloned() else {
+        return rsx! {
+            p {
+                button {
+                    onclick: move |_| {
+                        hunts.write().insert(pokemon(), Hunt::new(now()));
+                    },
+                    "Start hunt"
+                }
+            }
+        };
+    };
+
+    let mut update = move |f: &dyn Fn(&mut Hunt)| {
+        if let Some(hunt) = hunts.write().get_mut(&pokemon()) {
+            f(hunt);
+        }
+    };
+
+    rsx! {
+        p {
+            "Method: ",
+            select {
+                oninput: move |e| {
+                    let method = HuntMethod::ALL
+                        .into_iter()
+                        .find(|m| m.label() == e.data.value())
+                        .unwrap_or_default();
+                    update(&|h| h.method = method);
+                },
+                for method in HuntMethod::ALL {
+                    option {
+                        value: method.label(),
+                        selected: method == hunt.method,
+                        "{method.label()}"
+                    }
+                }
+            }
+        }
+        if hunt.method == HuntMethod::Outbreak {
+            p {
+                "Juggling several outbreaks? Try the "
+                a { href: "/shiny/outbreaks", "outbreak assistant" }
+            }
+        }
+        p {
+            "Shiny Charm: ",
+            input {
+                r#type: "checkbox",
+                oninput: move |_| update(&|h| h.shiny_charm = !h.shiny_charm),
+                checked: hunt.shiny_charm
+            }
+        }
+        if hunt.method != HuntMethod::Masuda {
+            p {
+                "Sandwich Level: ",
+                select {
+                    oninput: move |e| {
+                        let level = e.data.value().parse::<i64>().unwrap_or_default();
+                        update(&|h| h.sandwich_level = level);
+                    },
+                    for i in [0, 1, 2, 3] {
+                        option { value: "{i}", selected: i == hunt.sandwich_level, b { "{i}" } }
+                    }
+                }
+            }
+        }
+        p {
+            button {
+                style: "margin-right: 10px;",
+                disabled: hunt.completed_at.is_some(),
+                onclick: move |_| update(&|h| h.encounter(now())),
+                "Encounter +"
+            }
+            b { "{hunt.encounters}" }
+            button {
+                style: "margin-left: 10px;",
+                disabled: hunt.completed_at.is_some(),
+                onclick: move |_| update(&|h| h.undo_encounter()),
+                "Encounter -"
+            }
+        }
+        p {
+            "Started: {format_timestamp(hunt.started_at)} | Elapsed: "
+            b { "{format_duration(hunt.elapsed(clock()))}" }
+            if hunt.completed_at.is_none() {
+                button {
+                    style: "margin-left: 10px;",
+                    onclick: move |_| {
+                        update(&|h| if h.session_open { h.pause(now()) } else { h.resume(now()) })
+                    },
+                    if hunt.session_open { "Pause" } else { "Resume" }
+                }
+            }
+        }
+        if let Some(completed_at) = hunt.completed_at {
+            p { "Found after {hunt.encounters} encounters on {format_timestamp(completed_at)}" }
+        } else {
+            p {
+                input {
+                    r#type: "text",
+                    placeholder: "Off-target shiny",
+                    value: "{phase_name}",
+                    oninput: move |e| phase_name.set(e.data.value()),
+                }
+                button {
+                    style: "margin-left: 10px;",
+                    onclick: move |_| {
+                        let name = phase_name();
+                        update(&|h| h.phase(name.clone(), now()));
+                        phase_name.set(String::new());
+                    },
+                    "Log phase"
+                }
+                button {
+                    style: "margin-left: 10px;",
+                    onclick: move |_| update(&|h| h.complete(now())),
+                    "Found it!"
+                }
+            }
+        }
+        if !hunt.phases.is_empty() {
+            b { "Phases" }
+            ul {
+                for phase in hunt.phases.iter() {
+                    li { "{phase.pokemon} after {phase.encounters} encounters" }
+                }
+            }
+        }
+        if !hunt.sessions.is_empty() {
+            b { "Sessions" }
+            table {
+                thead {
+                    tr {
+                        th { "Started" }
+                        th { "Duration" }
+                        th { "Encounters" }
+                    }
+                }
+                tbody {
+                    for session in hunt.sessions.iter().rev() {
+                        tr {
+                            td { "{format_timestamp(session.started_at)}" }
+                            td { "{format_duration(session.ended_at - session.started_at)}" }
+                            td { "{session.encounters}" }
+                        }
+                    }
+                }
+            }
+        }
+        p {
+            button {
+                onclick: move |_| {
+                    hunts.write().remove(&pokemon());
+                },
+                "Abandon hunt"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completed_hunts_ignore_encounters() {
+        let mut hunt = Hunt::new(0.0);
+        hunt.encounter(10.0);
+        hunt.complete(20.0);
+
+        hunt.encounter(30.0);
+
+        assert_eq!(hunt.encounters, 1);
+        assert_eq!(hunt.sessions.len(), 1);
+        assert!(!hunt.session_open);
+    }
+}
@@ -0,0 +1,129 @@
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+use crate::footer;
+use crate::shiny_dex::recipe_finder::{find_recipes, sparkling_targets, Ranking};
+use crate::shiny_dex::sandwich::{ingredient_names, IngredientKind, Sandwich, SandwichResult};
+use crate::shiny_dex::storage::{use_stored, InventoryKey};
+
+/// How many of each ingredient the user has, keyed by ingredient name.
+pub type Inventory = HashMap<String, i64>;
+
+/// How many of each ingredient a sandwich uses.
+pub fn ingredient_counts(sandwich: &Sandwich) -> HashMap<&str, i64> {
+    let mut counts = HashMap::new();
+    for name in sandwich.fillings.iter().chain(sandwich.seasonings.iter()) {
+        *counts.entry(name.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn can_make(inventory: &Inventory, sandwich: &Sandwich) -> bool {
+    ingredient_counts(sandwich)
+        .into_iter()
+        .all(|(name, count)| inventory.get(name).copied().unwrap_or_default() >= count)
+}
+
+/// Removes a sandwich's ingredients from the inventory, never going below zero.
+pub fn consume(inventory: &mut Inventory, sandwich: &Sandwich) {
+    for (name, count) in ingredient_counts(sandwich) {
+        if let Some(owned) = inventory.get_mut(name) {
+            *owned = (*owned - count).max(0);
+        }
+    }
+}
+
+#[component]
+pub fn SandwichInventory() -> Element {
+    let inventory = use_stored::<InventoryKey>();
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", justify_content: "space-between",
+            h1 { "Sandwich ingredients" }
+            h1 { a { href: "/shiny", "Back to the shiny dex" } }
+        }
+        div { display: "flex", flex_direction: "row",
+            div { margin: "10px", width: "50%",
+                h2 { class: "text-xl", "Fillings" }
+                InventoryList { inventory, kind: IngredientKind::Filling }
+            }
+            div { margin: "10px", width: "50%",
+                h2 { class: "text-xl", "Seasonings" }
+                InventoryList { inventory, kind: IngredientKind::Seasoning }
+            }
+        }
+        footer::Footer {}
+    }
+}
+
+#[component]
+fn InventoryList(inventory: Signal<Inventory>, kind: IngredientKind) -> Element {
+    rsx! {
+        table { border_collapse: "collapse",
+            tbody {
+                for name in ingredient_names(kind) {
+                    tr { class: "border-2",
+                        td { "{name}" }
+                        td {
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                value: "{inventory.read().get(name).copied().unwrap_or_default()}",
+                                oninput: move |e| {
+                                    let count = e.data.value().parse::<i64>().unwrap_or_default().max(0);
+                                    inventory.write().insert(name.to_string(), count);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sparkling recipes for the given types that can be made from the inventory,
+/// searched for on demand as the search can take a moment.
+#[component]
+pub fn MakeableRecipes(
+    pokemon_types: ReadOnlySignal<Vec<String>>,
+    inventory: ReadOnlySignal<Inventory>,
+    on_log: EventHandler<Sandwich>,
+) -> Element {
+    let mut recipes = use_signal(Vec::<Sandwich>::new);
+    let mut searched = use_signal(|| false);
+
+    rsx! {
+        button {
+            onclick: move |_| {
+                let inventory = inventory.read();
+                recipes.set(
+                    pokemon_types()
+                        .iter()
+                        .flat_map(|t| {
+                            find_recipes(&sparkling_targets(t), Ranking::Ingredients, 3, Some(&inventory))
+                        })
+                        .collect(),
+                );
+                searched.set(true);
+            },
+            "Find sandwiches I can make"
+        }
+        if searched() && recipes.read().is_empty() {
+            p {
+                "None of the sparkling recipes can be made with your "
+                a { href: "/shiny/inventory", "ingredients" }
+            }
+        }
+        for recipe in recipes() {
+            p { b { "{recipe}" } }
+            SandwichResult { sandwich: recipe.clone() }
+            button {
+                // The list isn't redone as ingredients are used, so check it's still makeable.
+                disabled: !can_make(&inventory.read(), &recipe),
+                onclick: move |_| on_log.call(recipe.clone()),
+                "Log sandwich"
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+use std::collections::BTreeMap;
+
+use crate::shiny_dex::exclusives::exclusive_version;
+use crate::shiny_dex::focus::gql_query;
+use crate::shiny_dex::sv_locations::{SV_AREAS, SV_LOCATIONS};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct EncounterLocations;
+
+/// Where and how a pokemon can be found, merged across encounter slots.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncounterLocation {
+    pub area: String,
+    pub method: String,
+    pub min_level: i64,
+    pub max_level: i64,
+    pub versions: Vec<String>,
+}
+
+/// Extra encounter data for games PokeAPI has little or nothing on.
+pub trait LocationSupplement: Sync {
+    /// The versions this supplement covers, used to tell if PokeAPI already has them.
+    fn versions(&self) -> &'static [&'static str];
+    fn locations(&self, pokemon: &str) -> Vec<EncounterLocation>;
+}
+
+/// Supplements consulted, in order, when PokeAPI lacks a game's encounters.
+static SUPPLEMENTS: &[&dyn LocationSupplement] = &[&ScarletViolet];
+
+/// Bundled Scarlet and Violet locations, as PokeAPI has no encounters for
+/// Paldea or the DLC areas.
+struct ScarletViolet;
+
+impl LocationSupplement for ScarletViolet {
+    fn versions(&self) -> &'static [&'static str] {
+        &["scarlet", "violet"]
+    }
+
+    fn locations(&self, pokemon: &str) -> Vec<EncounterLocation> {
+        // Version exclusives are only found in their own version.
+        let versions = match exclusive_version(pokemon) {
+            Some(version) => vec![version.name().to_string()],
+            None => self.versions().iter().map(|v| v.to_string()).collect(),
+        };
+        SV_LOCATIONS
+            .get(pokemon)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|(area, method)| {
+                        let (min_level, max_level) =
+                            SV_AREAS.get(area).copied().unwrap_or_default();
+                        EncounterLocation {
+                            area: area.to_string(),
+                            method: method.to_string(),
+                            min_level,
+                            max_level,
+                            versions: versions.clone(),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Merges raw encounters into one entry per area and method, widening the
+/// level range and collecting the versions it appears in.
+fn merge_encounters(
+    encounters: Vec<encounter_locations::EncounterLocationsPokemonV2Encounter>,
+) -> Vec<EncounterLocation> {
+    let mut merged = BTreeMap::<(String, String), EncounterLocation>::new();
+    for encounter in encounters {
+        let area = encounter
+            .pokemon_v2_locationarea
+            .map(|a| a.name)
+            .unwrap_or_default();
+        let method = encounter
+            .pokemon_v2_encounterslot
+            .and_then(|s| s.pokemon_v2_encountermethod)
+            .map(|m| m.name)
+            .unwrap_or_default();
+        let version = encounter
+            .pokemon_v2_version
+            .map(|v| v.name)
+            .unwrap_or_default();
+
+        let location = merged
+            .entry((area.clone(), method.clone()))
+            .or_insert_with(|| EncounterLocation {
+                area,
+                method,
+                min_level: encounter.min_level,
+                max_level: encounter.max_level,
+                versions: Vec::new(),
+            });
+        location.min_level = location.min_level.min(encounter.min_level);
+        location.max_level = location.max_level.max(encounter.max_level);
+        if !location.versions.contains(&version) {
+            location.versions.push(version);
+        }
+    }
+    merged.into_values().collect()
+}
+
+pub async fn load_locations(pokemon: String) -> Result<Vec<EncounterLocation>, String> {
+    let variables = encounter_locations::Variables {
+        name: pokemon.clone(),
+    };
+    let data = gql_query::<EncounterLocations>(variables)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut locations = merge_encounters(data.pokemon_v2_encounter);
+
+    for supplement in SUPPLEMENTS {
+        let covered = locations.iter().any(|l| {
+            l.versions
+                .iter()
+                .any(|v| supplement.versions().contains(&v.as_str()))
+        });
+        if !covered {
+            locations.extend(supplement.locations(&pokemon));
+        }
+    }
+    Ok(locations)
+}
+
+#[component]
+pub fn Locations(pokemon: ReadOnlySignal<String>) -> Element {
+    let locations = use_resource(move || load_locations(pokemon()));
+
+    match &*locations.read_unchecked() {
+        Some(Ok(locations)) => rsx! {
+            if locations.is_empty() {
+                p { "No known wild encounters" }
+            } else {
+                table { border_collapse: "collapse",
+                    thead {
+                        tr {
+                            th { "Area" }
+                            th { "Method" }
+                            th { "Levels" }
+                            th { "Versions" }
+                        }
+                    }
+                    tbody {
+                        for location in locations.iter() {
+                            tr { class: "border-2",
+                                td { "{location.area}" }
+                                td { "{location.method}" }
+                                td {
+                                    if location.min_level == location.max_level {
+                                        "{location.min_level}"
+                                    } else {
+                                        "{location.min_level}-{location.max_level}"
+                                    }
+                                }
+                                td { "{location.versions.join(\", \")}" }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        Some(Err(err)) => rsx! {"An error occurred while loading locations {err}"},
+        None => rsx! {"Loading locations"},
+    }
+}
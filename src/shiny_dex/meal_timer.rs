@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use dioxus_sdk::utils::timing::use_interval;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::shiny_dex::sandwich::Sandwich;
+use crate::shiny_dex::storage::{meal_timer_notified_key, use_stored, MealTimerKey, ProfilesKey};
+use crate::time::{format_duration, now};
+
+/// Meal powers last 30 minutes.
+const MEAL_DURATION: f64 = 30.0 * 60.0 * 1000.0;
+/// How long before expiry to warn that it's time to make another sandwich.
+const WARNING_BEFORE: f64 = 5.0 * 60.0 * 1000.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MealTimer {
+    pub sandwich: Sandwich,
+    pub eaten_at: f64,
+    pub warned: bool,
+    pub expired: bool,
+}
+
+impl MealTimer {
+    pub fn new(sandwich: Sandwich, eaten_at: f64) -> Self {
+        Self {
+            sandwich,
+            eaten_at,
+            warned: false,
+            expired: false,
+        }
+    }
+
+    pub fn remaining(&self, now: f64) -> f64 {
+        (self.eaten_at + MEAL_DURATION - now).max(0.0)
+    }
+}
+
+pub fn use_meal_timer() -> Signal<Option<MealTimer>> {
+    use_stored::<MealTimerKey>()
+}
+
+/// Starts the timer for a freshly eaten sandwich.
+pub fn eat(mut meal_timer: Signal<Option<MealTimer>>, sandwich: Sandwich) {
+    request_notification_permission();
+    meal_timer.set(Some(MealTimer::new(sandwich, now())));
+}
+
+fn request_notification_permission() {
+    document::eval(
+        r#"
+        if ("Notification" in window && Notification.permission === "default") {
+            Notification.requestPermission();
+        }"#,
+    );
+}
+
+/// Shows a notification, from whichever open tab gets to it first. Each tab
+/// runs the timer, so the first claims `tag` in the profile's local storage
+/// and the rest skip it; the tag also has the browser replace any duplicate
+/// that slips through rather than show it twice.
+fn notify(profile: &str, title: &str, body: &str, tag: &str) {
+    let key = serde_json::to_string(&meal_timer_notified_key(profile)).unwrap_or_default();
+    let title = serde_json::to_string(title).unwrap_or_default();
+    let body = serde_json::to_string(body).unwrap_or_default();
+    let tag = serde_json::to_string(tag).unwrap_or_default();
+    document::eval(&format!(
+        r#"
+        if ("Notification" in window && Notification.permission === "granted"
+            && localStorage.getItem({key}) !== {tag}) {{
+            localStorage.setItem({key}, {tag});
+            new Notification({title}, {{ body: {body}, tag: {tag} }});
+        }}"#
+    ));
+}
+
+#[component]
+pub fn MealTimerBadge() -> Element {
+    let mut meal_timer = use_meal_timer();
+    let profiles = use_stored::<ProfilesKey>();
+    let mut clock = use_signal(now);
+
+    use_interval(Duration::from_secs(1), move || {
+        let now = now();
+        clock.set(now);
+
+        let Some(timer) = meal_timer.peek().clone() else {
+            return;
+        };
+        let profile = profiles.peek().active.clone();
+        let remaining = timer.remaining(now);
+        if remaining <= 0.0 && !timer.expired {
+            notify(
+                &profile,
+                "Meal powers expired",
+                &format!("Time to remake {}", timer.sandwich),
+                &format!("meal-expired-{}", timer.eaten_at),
+            );
+            if let Some(timer) = meal_timer.write().as_mut() {
+                timer.warned = true;
+                timer.expired = true;
+            }
+        } else if remaining <= WARNING_BEFORE && !timer.warned {
+            notify(
+                &profile,
+                "Meal powers ending soon",
+                "Your meal powers run out in 5 minutes",
+                &format!("meal-warning-{}", timer.eaten_at),
+            );
+            if let Some(timer) = meal_timer.write().as_mut() {
+                timer.warned = true;
+            }
+        }
+    });
+
+    let Some(timer) = meal_timer() else {
+        return rsx! {};
+    };
+    let remaining = timer.remaining(clock());
+
+    rsx! {
+        span { title: "{timer.sandwich}",
+            if remaining > 0.0 {
+                "Meal powers: {format_duration(remaining)}"
+            } else {
+                "Meal powers expired"
+            }
+            button {
+                style: "margin-left: 10px;",
+                onclick: move |_| meal_timer.set(None),
+                "✕"
+            }
+        }
+    }
+}
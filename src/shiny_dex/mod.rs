@@ -1,9 +1,28 @@
 use phf::phf_map;
 
+mod backup;
+mod caught;
 mod dex_by_type;
+mod evolution;
+mod exclusives;
 mod favourites;
 mod focus;
+mod hunt;
+mod inventory;
+mod locations;
+mod meal_timer;
+mod outbreak;
+mod planner;
+mod pokedexes;
+mod profiles;
+mod progress;
+mod recipe_finder;
+mod sandwich;
 mod shiny_dex;
+mod shiny_lock;
+mod storage;
+mod sv_locations;
+mod sync;
 
 struct TypeInfo {
     pub color: &'static str,
@@ -86,6 +105,14 @@ static TYPES_INFO: phf::Map<&'static str, TypeInfo> = phf_map! {
 };
 
 pub mod prelude {
+    pub use crate::shiny_dex::backup::ImportExport;
     pub use crate::shiny_dex::favourites::Favourites;
+    pub use crate::shiny_dex::inventory::SandwichInventory;
+    pub use crate::shiny_dex::meal_timer::MealTimerBadge;
+    pub use crate::shiny_dex::outbreak::OutbreakAssistant;
+    pub use crate::shiny_dex::planner::HuntPlanner;
+    pub use crate::shiny_dex::profiles::ProfileSwitcher;
     pub use crate::shiny_dex::shiny_dex::ShinyDex;
+    pub use crate::shiny_dex::storage::use_storage_provider;
+    pub use crate::shiny_dex::sync::{use_sync_provider, DeviceSync, SyncBadge};
 }
@@ -0,0 +1,215 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::footer;
+use crate::shiny_dex::focus::{expected_encounters, shiny_odds};
+use crate::shiny_dex::storage::{use_stored, OutbreaksKey};
+use crate::time::{format_timestamp, now};
+
+/// An active mass outbreak and the KOs counted towards it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Outbreak {
+    pub pokemon: String,
+    pub location: String,
+    pub kos: i64,
+    pub sandwich_level: i64,
+    pub shiny_charm: bool,
+    pub started_at: f64,
+}
+
+impl Outbreak {
+    pub fn new(pokemon: String, location: String, started_at: f64) -> Self {
+        Self {
+            pokemon,
+            location,
+            started_at,
+            ..Default::default()
+        }
+    }
+
+    /// The bonus tier reached, which adds one roll at 30 KOs and two at 60.
+    pub fn tier(&self) -> &'static str {
+        match self.kos {
+            0..=29 => "0-29 KOs",
+            30..=59 => "30-59 KOs",
+            _ => "60+ KOs",
+        }
+    }
+
+    /// KOs left until the next tier, if there is one.
+    pub fn next_tier(&self) -> Option<i64> {
+        [30, 60]
+            .into_iter()
+            .find(|threshold| self.kos < *threshold)
+            .map(|threshold| threshold - self.kos)
+    }
+
+    pub fn odds(&self) -> (f64, i64) {
+        shiny_odds(self.kos, self.sandwich_level, self.shiny_charm)
+    }
+}
+
+pub type Outbreaks = Vec<Outbreak>;
+
+#[component]
+pub fn OutbreakAssistant() -> Element {
+    let mut outbreaks = use_stored::<OutbreaksKey>();
+    let mut active = use_signal(|| 0usize);
+    let mut pokemon = use_signal(String::new);
+    let mut location = use_signal(String::new);
+
+    let mut update = move |i: usize, f: &dyn Fn(&mut Outbreak)| {
+        if let Some(outbreak) = outbreaks.write().get_mut(i) {
+            f(outbreak);
+        }
+    };
+
+    // Shortcuts act on the active outbreak: space or + counts a KO, - or
+    // backspace undoes one, and 1-9 switch between outbreaks.
+    let onkeydown = move |e: KeyboardEvent| match e.key() {
+        Key::Character(c) if c == " " || c == "+" || c == "=" => {
+            e.prevent_default();
+            update(active(), &|o| o.kos += 1);
+        }
+        Key::Character(c) if c == "-" => update(active(), &|o| o.kos = (o.kos - 1).max(0)),
+        Key::Backspace => update(active(), &|o| o.kos = (o.kos - 1).max(0)),
+        Key::Character(c) => {
+            if let Ok(n @ 1..=9) = c.parse::<usize>() {
+                if n <= outbreaks.read().len() {
+                    active.set(n - 1);
+                }
+            }
+        }
+        _ => {}
+    };
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", justify_content: "space-between",
+            h1 { "Mass outbreaks" }
+            h1 { a { href: "/shiny", "Back to the shiny dex" } }
+        }
+        p {
+            input {
+                r#type: "text",
+                placeholder: "Pokemon",
+                value: "{pokemon}",
+                oninput: move |e| pokemon.set(e.data.value()),
+            }
+            input {
+                style: "margin-left: 10px;",
+                r#type: "text",
+                placeholder: "Location",
+                value: "{location}",
+                oninput: move |e| location.set(e.data.value()),
+            }
+            button {
+                style: "margin-left: 10px;",
+                disabled: pokemon.read().trim().is_empty(),
+                onclick: move |_| {
+                    outbreaks
+                        .write()
+                        .push(Outbreak::new(pokemon().trim().to_lowercase(), location().trim().to_string(), now()));
+                    active.set(outbreaks.read().len() - 1);
+                    pokemon.set(String::new());
+                    location.set(String::new());
+                },
+                "Add outbreak"
+            }
+        }
+        if outbreaks.read().is_empty() {
+            p { "No outbreaks yet, add the ones active in your game" }
+        } else {
+            p { "Click the list then use space or + to count a KO, - to undo, and 1-9 to switch outbreak" }
+        }
+        div { tabindex: "0", onkeydown,
+            for (i, outbreak) in outbreaks().into_iter().enumerate() {
+                div {
+                    class: "border-2",
+                    margin: "10px",
+                    padding: "10px",
+                    border_color: if i == active() { "#EE8130" } else { "" },
+                    onclick: move |_| active.set(i),
+                    div { display: "flex", flex_direction: "row", justify_content: "space-between",
+                        b { "{i + 1}. {outbreak.pokemon}" }
+                        span { "{outbreak.location} | since {format_timestamp(outbreak.started_at)}" }
+                    }
+                    // Keys pressed on the controls are for them, not a KO.
+                    div { onkeydown: move |e| e.stop_propagation(),
+                        OutbreakCounter { outbreaks, index: i }
+                        button {
+                            onclick: move |e| {
+                                // Don't let the card make the removed outbreak active again.
+                                e.stop_propagation();
+                                outbreaks.write().remove(i);
+                                active.set(active().min(outbreaks.read().len().saturating_sub(1)));
+                            },
+                            "Remove outbreak"
+                        }
+                    }
+                }
+            }
+        }
+        footer::Footer {}
+    }
+}
+
+#[component]
+fn OutbreakCounter(outbreaks: Signal<Outbreaks>, index: usize) -> Element {
+    let Some(outbreak) = outbreaks.read().get(index).cloned() else {
+        return rsx! {};
+    };
+    let (odds, rolls) = outbreak.odds();
+
+    let mut update = move |f: &dyn Fn(&mut Outbreak)| {
+        if let Some(outbreak) = outbreaks.write().get_mut(index) {
+            f(outbreak);
+        }
+    };
+
+    rsx! {
+        p {
+            button {
+                style: "margin-right: 10px;",
+                onclick: move |_| update(&|o| o.kos += 1),
+                "KO +"
+            }
+            b { "{outbreak.kos}" }
+            button {
+                style: "margin-left: 10px;",
+                onclick: move |_| update(&|o| o.kos = (o.kos - 1).max(0)),
+                "KO -"
+            }
+            " Tier: "
+            b { "{outbreak.tier()}" }
+            if let Some(left) = outbreak.next_tier() {
+                " ({left} to the next tier)"
+            }
+        }
+        p {
+            "Shiny Charm: ",
+            input {
+                r#type: "checkbox",
+                oninput: move |_| update(&|o| o.shiny_charm = !o.shiny_charm),
+                checked: outbreak.shiny_charm
+            }
+            " Sandwich Level: ",
+            select {
+                oninput: move |e| {
+                    let level = e.data.value().parse::<i64>().unwrap_or_default();
+                    update(&|o| o.sandwich_level = level);
+                },
+                for i in [0, 1, 2, 3] {
+                    option { value: "{i}", selected: i == outbreak.sandwich_level, b { "{i}" } }
+                }
+            }
+        }
+        p {
+            "Odds:"
+            b { "{odds * 100.0:.3}%" }
+            " | Rolls:"
+            b { "{rolls}" }
+            " of 4096 | Expected encounters: "
+            b { "{expected_encounters(odds)}" }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+use std::collections::BTreeSet;
+
+use crate::footer;
+use crate::shiny_dex::favourites::favourite_names;
+use crate::shiny_dex::focus::{expected_encounters, gql_query, shiny_odds};
+use crate::shiny_dex::hunt::Hunts;
+use crate::shiny_dex::sandwich::Sandwich;
+use crate::shiny_dex::shiny_dex::{DEXES, TYPES};
+use crate::shiny_dex::storage::{use_stored, CaughtKey, CollectionsKey, HuntsKey};
+use crate::shiny_dex::TYPES_INFO;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct HuntTargets;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Target {
+    pub name: String,
+    pub types: Vec<String>,
+    pub dexes: Vec<String>,
+}
+
+impl From<hunt_targets::HuntTargetsPokemonV2Pokemon> for Target {
+    fn from(pokemon: hunt_targets::HuntTargetsPokemonV2Pokemon) -> Self {
+        Self {
+            name: pokemon.name,
+            types: pokemon
+                .pokemon_v2_pokemontypes
+                .into_iter()
+                .filter_map(|t| t.pokemon_v2_type.map(|t| t.name))
+                .collect(),
+            dexes: pokemon
+                .pokemon_v2_pokemonspecy
+                .into_iter()
+                .flat_map(|s| s.pokemon_v2_pokemondexnumbers)
+                .filter_map(|n| n.pokemon_v2_pokedex.map(|d| d.name))
+                .collect(),
+        }
+    }
+}
+
+/// Targets that can all be hunted in one dex with one type of sandwich.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HuntGroup {
+    pub dex: &'static str,
+    pub pokemon_type: &'static str,
+    pub targets: Vec<String>,
+}
+
+/// Groups targets so each sandwich covers as many of them as possible.
+///
+/// Greedily picks the dex and type shared by the most remaining targets,
+/// preferring earlier entries of `DEXES` and `TYPES` on ties. Returns the
+/// groups in hunting order, and the targets not found in any dex.
+pub fn plan_hunts(targets: &[Target]) -> (Vec<HuntGroup>, Vec<String>) {
+    let mut remaining = targets.iter().collect::<Vec<_>>();
+    let mut groups = Vec::new();
+
+    loop {
+        let covers = |dex: &str, pokemon_type: &str| {
+            remaining
+                .iter()
+                .filter(|t| t.dexes.iter().any(|d| d == dex))
+                .filter(|t| t.types.iter().any(|t| t == pokemon_type))
+                .count()
+        };
+        let best = DEXES
+            .iter()
+            .flat_map(|dex| TYPES.iter().map(move |t| (*dex, *t)))
+            .map(|(dex, t)| (dex, t, covers(dex, t)))
+            .filter(|(_, _, count)| *count > 0)
+            .rev()
+            .max_by_key(|(_, _, count)| *count);
+        let Some((dex, pokemon_type, _)) = best else {
+            break;
+        };
+
+        let (covered, rest): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|t| {
+            t.dexes.iter().any(|d| d == dex) && t.types.iter().any(|t| t == pokemon_type)
+        });
+        remaining = rest;
+        groups.push(HuntGroup {
+            dex,
+            pokemon_type,
+            targets: covered.into_iter().map(|t| t.name.clone()).collect(),
+        });
+    }
+
+    (
+        groups,
+        remaining.into_iter().map(|t| t.name.clone()).collect(),
+    )
+}
+
+#[component]
+pub fn HuntPlanner() -> Element {
+    let faves = use_stored::<CollectionsKey>();
+    let hunts = use_stored::<HuntsKey>();
+    let caught = use_stored::<CaughtKey>();
+    let include_faves = use_signal(|| true);
+    let include_hunts = use_signal(|| true);
+    let skip_owned = use_signal(|| true);
+    let shiny_charm = use_signal(|| false);
+
+    let names = use_memo(move || {
+        let mut names = BTreeSet::new();
+        if include_faves() {
+            names.extend(favourite_names(&faves.read()));
+        }
+        if include_hunts() {
+            names.extend(
+                hunts()
+                    .into_iter()
+                    .filter(|(_, hunt)| hunt.completed_at.is_none())
+                    .map(|(name, _)| name),
+            );
+        }
+        if skip_owned() {
+            names.retain(|name| !caught.read().contains_key(name));
+        }
+        names.into_iter().collect::<Vec<_>>()
+    });
+
+    let targets = use_resource(move || async move {
+        let variables = hunt_targets::Variables {
+            names: names(),
+            dexes: DEXES.iter().map(|d| d.to_string()).collect(),
+        };
+        gql_query::<HuntTargets>(variables).await.map(|data| {
+            data.pokemon_v2_pokemon
+                .into_iter()
+                .map(Target::from)
+                .collect::<Vec<_>>()
+        })
+    });
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", justify_content: "space-between",
+            h1 { "Hunt planner" }
+            h1 { a { href: "/shiny", "Back to the shiny dex" } }
+        }
+        div { display: "flex", flex_direction: "row",
+            PlannerToggle { label: "Favourites", value: include_faves }
+            PlannerToggle { label: "Active hunts", value: include_hunts }
+            PlannerToggle { label: "Skip owned", value: skip_owned }
+            PlannerToggle { label: "Shiny Charm", value: shiny_charm }
+        }
+        match &*targets.read_unchecked() {
+            Some(Ok(targets)) => rsx! {
+                Schedule { targets: targets.clone(), hunts, shiny_charm }
+            },
+            Some(Err(err)) => rsx! {"An error occurred while loading targets {err}"},
+            _ => rsx! {"Loading targets"},
+        }
+        footer::Footer {}
+    }
+}
+
+#[component]
+fn PlannerToggle(label: &'static str, value: Signal<bool>) -> Element {
+    rsx! {
+        label { class: "py-2 px-4",
+            input {
+                r#type: "checkbox",
+                oninput: move |_| value.set(!value()),
+                checked: value()
+            }
+            " {label}"
+        }
+    }
+}
+
+#[component]
+fn Schedule(
+    targets: ReadOnlySignal<Vec<Target>>,
+    hunts: ReadOnlySignal<Hunts>,
+    shiny_charm: ReadOnlySignal<bool>,
+) -> Element {
+    // Encounters still expected for a target, using its hunt's settings if it has one.
+    let estimate = move |name: &str| match hunts.read().get(name) {
+        Some(hunt) => (expected_encounters(hunt.odds().0) - hunt.encounters).max(0),
+        None => expected_encounters(shiny_odds(0, 3, shiny_charm()).0),
+    };
+
+    let (mut groups, unplanned) = plan_hunts(&targets.read());
+    for group in groups.iter_mut() {
+        group.targets.sort_by_key(|name| estimate(name));
+    }
+
+    rsx! {
+        if groups.is_empty() && unplanned.is_empty() {
+            p { "Nothing to plan, favourite some pokemon or start a hunt first" }
+        }
+        ol {
+            for group in groups {
+                li { margin: "10px",
+                    div { display: "flex", flex_direction: "row", align_items: "center",
+                        div {
+                            overflow: "hidden",
+                            background_color: TYPES_INFO.get(group.pokemon_type).unwrap().color,
+                            border_radius: "50%",
+                            width: "40px",
+                            height: "40px",
+                            margin_right: "10px",
+                            img { src: "/icons/{group.pokemon_type}.svg" }
+                        }
+                        b { "{group.dex} · {group.pokemon_type}" }
+                        " | {Sandwich::sparkling(group.pokemon_type)}"
+                    }
+                    ul {
+                        for name in group.targets.iter() {
+                            li { "{name}: ~{estimate(name)} encounters" }
+                        }
+                    }
+                    p { "Total: ~{group.targets.iter().map(|n| estimate(n)).sum::<i64>()} encounters" }
+                }
+            }
+        }
+        if !unplanned.is_empty() {
+            b { "Not found in any of {DEXES.join(\", \")}" }
+            ul {
+                for name in unplanned {
+                    li { "{name}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(name: &str, types: &[&str], dexes: &[&str]) -> Target {
+        Target {
+            name: name.to_string(),
+            types: types.iter().map(|t| t.to_string()).collect(),
+            dexes: dexes.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn one_type_covers_several_targets() {
+        let (groups, missing) = plan_hunts(&[
+            target("pikachu", &["electric"], &["paldea"]),
+            target("magnemite", &["electric", "steel"], &["paldea"]),
+            target("tinkatink", &["fairy", "steel"], &["paldea"]),
+        ]);
+        assert_eq!(
+            groups,
+            vec![
+                HuntGroup {
+                    dex: "paldea",
+                    pokemon_type: "electric",
+                    targets: vec!["pikachu".to_string(), "magnemite".to_string()],
+                },
+                HuntGroup {
+                    dex: "paldea",
+                    pokemon_type: "steel",
+                    targets: vec!["tinkatink".to_string()],
+                },
+            ]
+        );
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn ties_prefer_earlier_dexes_and_types() {
+        let (groups, _) = plan_hunts(&[target(
+            "dratini",
+            &["water", "dragon"],
+            &["blueberry", "kitakami"],
+        )]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].dex, "kitakami");
+        assert_eq!(groups[0].pokemon_type, "water");
+    }
+
+    #[test]
+    fn targets_in_no_dex_are_returned_separately() {
+        let (groups, missing) = plan_hunts(&[
+            target("bulbasaur", &["grass", "poison"], &[]),
+            target("sprigatito", &["grass"], &["paldea"]),
+        ]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].targets, vec!["sprigatito".to_string()]);
+        assert_eq!(missing, vec!["bulbasaur".to_string()]);
+    }
+}
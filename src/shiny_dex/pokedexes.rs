@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+
+use crate::shiny_dex::focus::gql_query;
+use crate::shiny_dex::shiny_dex::DEXES;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct Pokedexes;
+
+/// Dexes sharing a version group (or a region, for dexes not tied to a game).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DexGroup {
+    pub label: String,
+    pub order: i64,
+    pub dexes: Vec<String>,
+}
+
+fn group_dexes(pokedexes: Vec<pokedexes::PokedexesPokemonV2Pokedex>) -> Vec<DexGroup> {
+    let mut groups: Vec<DexGroup> = Vec::new();
+    for dex in pokedexes {
+        let version_group = dex
+            .pokemon_v2_pokedexversiongroups
+            .iter()
+            .filter_map(|v| v.pokemon_v2_versiongroup.as_ref())
+            .min_by_key(|v| v.order.unwrap_or(i64::MAX));
+        let (label, order) = match (version_group, &dex.pokemon_v2_region) {
+            (Some(v), _) => (v.name.clone(), v.order.unwrap_or(i64::MAX)),
+            (None, Some(region)) => (region.name.clone(), i64::MAX),
+            (None, None) => ("other".to_string(), i64::MAX),
+        };
+        match groups.iter_mut().find(|g| g.label == label) {
+            Some(group) => group.dexes.push(dex.name),
+            None => groups.push(DexGroup {
+                label,
+                order,
+                dexes: vec![dex.name],
+            }),
+        }
+    }
+    groups.sort_by_key(|g| g.order);
+    groups
+}
+
+/// Selects a dex from every main series dex PokeAPI knows, grouped by game.
+#[component]
+pub fn DexSelect(dex: Signal<String>) -> Element {
+    let groups = use_resource(|| async move {
+        gql_query::<Pokedexes>(pokedexes::Variables {})
+            .await
+            .map(|data| group_dexes(data.pokemon_v2_pokedex))
+    });
+
+    // Until the list loads, or if it fails, offer the Scarlet and Violet dexes.
+    let groups = match &*groups.read_unchecked() {
+        Some(Ok(groups)) if !groups.is_empty() => groups.clone(),
+        _ => vec![DexGroup {
+            label: "scarlet-violet".to_string(),
+            order: 0,
+            dexes: DEXES.iter().map(|d| d.to_string()).collect(),
+        }],
+    };
+
+    rsx! {
+        select {
+            class: "bg-white font-bold py-2 px-4 rounded",
+            width: "20%",
+            oninput: move |event| {
+                dex.set(event.data.value().clone());
+            },
+            for group in groups {
+                optgroup { label: "{group.label}",
+                    for d in group.dexes {
+                        option { value: "{d}", selected: d == dex(), "{d}" }
+                    }
+                }
+            }
+        }
+    }
+}
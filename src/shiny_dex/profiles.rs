@@ -0,0 +1,92 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::shiny_dex::storage::{use_stored, ProfilesKey};
+
+/// The profile holding the data from before there were profiles.
+pub const DEFAULT_PROFILE: &str = "Default";
+
+/// Trainer profiles sharing this browser, each with its own stored data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Profiles {
+    pub names: Vec<String>,
+    pub active: String,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            names: vec![DEFAULT_PROFILE.to_string()],
+            active: DEFAULT_PROFILE.to_string(),
+        }
+    }
+}
+
+#[component]
+pub fn ProfileSwitcher() -> Element {
+    let mut profiles = use_stored::<ProfilesKey>();
+    let mut adding = use_signal(|| false);
+    let mut new_name = use_signal(String::new);
+
+    let mut add_profile = move || {
+        let name = new_name().trim().to_string();
+        if !name.is_empty() && !profiles.read().names.contains(&name) {
+            let mut profiles = profiles.write();
+            profiles.names.push(name.clone());
+            profiles.active = name;
+        }
+        new_name.set(String::new());
+        adding.set(false);
+    };
+    let Profiles { names, active } = profiles();
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", align_items: "center",
+            "Trainer: "
+            select {
+                color: "black",
+                margin_left: "5px",
+                oninput: move |e| profiles.write().active = e.data.value(),
+                for name in names {
+                    option { value: "{name}", selected: name == active, "{name}" }
+                }
+            }
+            if adding() {
+                input {
+                    r#type: "text",
+                    color: "black",
+                    margin_left: "5px",
+                    placeholder: "Profile name",
+                    autofocus: true,
+                    value: "{new_name}",
+                    oninput: move |e| new_name.set(e.data.value()),
+                    onkeydown: move |e| match e.key() {
+                        Key::Enter => add_profile(),
+                        Key::Escape => adding.set(false),
+                        _ => {}
+                    }
+                }
+            } else {
+                button {
+                    margin_left: "5px",
+                    title: "Add a profile",
+                    onclick: move |_| adding.set(true),
+                    "+"
+                }
+            }
+            if active != DEFAULT_PROFILE {
+                button {
+                    margin_left: "5px",
+                    title: "Remove this profile. Its data is kept, so adding it again brings it back.",
+                    onclick: move |_| {
+                        let mut profiles = profiles.write();
+                        let active = profiles.active.clone();
+                        profiles.names.retain(|name| *name != active);
+                        profiles.active = DEFAULT_PROFILE.to_string();
+                    },
+                    "✕"
+                }
+            }
+        }
+    }
+}
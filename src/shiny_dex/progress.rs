@@ -0,0 +1,119 @@
+use dioxus::prelude::*;
+use graphql_client::GraphQLQuery;
+
+use crate::shiny_dex::caught::Caught;
+use crate::shiny_dex::focus::gql_query;
+use crate::shiny_dex::shiny_dex::{DEXES, TYPES};
+use crate::shiny_dex::TYPES_INFO;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "PartialEq, Clone, Default, Debug, Serialize, Deserialize"
+)]
+pub struct DexProgress;
+
+#[component]
+pub fn Progress(dex: ReadOnlySignal<String>, caught: ReadOnlySignal<Caught>) -> Element {
+    let pokemon = use_resource(move || async move {
+        // The selected dex may be from another game, so fetch it alongside the tracked ones.
+        let mut dexes = DEXES.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        if !dexes.contains(&dex()) {
+            dexes.push(dex());
+        }
+        let variables = dex_progress::Variables { dexes };
+        gql_query::<DexProgress>(variables)
+            .await
+            .map(|data| data.pokemon_v2_pokemon)
+    });
+
+    match &*pokemon.read_unchecked() {
+        Some(Ok(pokemon)) => rsx! {
+            RenderProgress { dex, caught, pokemon: pokemon.clone() }
+        },
+        Some(Err(err)) => rsx! {"An error occurred while loading progress {err}"},
+        _ => rsx! {"Loading progress"},
+    }
+}
+
+#[component]
+fn RenderProgress(
+    dex: ReadOnlySignal<String>,
+    caught: ReadOnlySignal<Caught>,
+    pokemon: ReadOnlySignal<Vec<dex_progress::DexProgressPokemonV2Pokemon>>,
+) -> Element {
+    let in_dex = |p: &dex_progress::DexProgressPokemonV2Pokemon, dex: &str| {
+        p.pokemon_v2_pokemonspecy
+            .iter()
+            .flat_map(|s| s.pokemon_v2_pokemondexnumbers.iter())
+            .any(|n| n.pokemon_v2_pokedex.as_ref().is_some_and(|d| d.name == dex))
+    };
+    let has_type = |p: &dex_progress::DexProgressPokemonV2Pokemon, pokemon_type: &str| {
+        p.pokemon_v2_pokemontypes.iter().any(|t| {
+            t.pokemon_v2_type
+                .as_ref()
+                .is_some_and(|t| t.name == pokemon_type)
+        })
+    };
+    let count = |filter: &dyn Fn(&dex_progress::DexProgressPokemonV2Pokemon) -> bool| {
+        let pokemon = pokemon.read();
+        let matching = pokemon.iter().filter(|p| filter(p));
+        let owned = matching
+            .clone()
+            .filter(|p| caught.read().contains_key(&p.name))
+            .count();
+        (owned, matching.count())
+    };
+
+    rsx! {
+        details {
+            summary { "Shiny living dex progress" }
+            div { display: "flex", flex_direction: "row",
+                div { margin: "10px", width: "30%",
+                    for d in DEXES.iter() {
+                        {
+                            let (owned, total) = count(&|p| in_dex(p, d));
+                            rsx! { ProgressBar { label: d.to_string(), owned, total, color: "grey" } }
+                        }
+                    }
+                }
+                div { margin: "10px", width: "70%", display: "flex", flex_direction: "row", flex_wrap: "wrap",
+                    for t in TYPES.iter() {
+                        {
+                            let (owned, total) = count(&|p| in_dex(p, &dex()) && has_type(p, t));
+                            rsx! {
+                                div { width: "33%",
+                                    ProgressBar {
+                                        label: t.to_string(),
+                                        owned,
+                                        total,
+                                        color: TYPES_INFO.get(t).unwrap().color,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProgressBar(label: String, owned: usize, total: usize, color: &'static str) -> Element {
+    let percent = if total == 0 {
+        0.0
+    } else {
+        owned as f64 * 100.0 / total as f64
+    };
+
+    rsx! {
+        div { margin: "4px",
+            "{label}: {owned} / {total}"
+            div { background_color: "#e5e7eb", height: "10px", width: "100%",
+                div { background_color: color, height: "10px", width: "{percent}%" }
+            }
+        }
+    }
+}
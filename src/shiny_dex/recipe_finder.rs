@@ -0,0 +1,450 @@
+use dioxus::prelude::*;
+
+use crate::shiny_dex::inventory::Inventory;
+use crate::shiny_dex::sandwich::{
+    ingredient_names, Ingredient, IngredientKind, MealPower, MealPowerResult, Sandwich,
+    SandwichResult, Totals, INGREDIENTS, MAX_FILLINGS, MAX_SEASONINGS,
+};
+use crate::shiny_dex::shiny_dex::TYPES;
+
+/// Upper bound on the number of sandwiches tried per search, to keep the page responsive.
+const MAX_EVALUATIONS: usize = 200_000;
+
+/// A meal power a recipe must grant, at or above `level`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerTarget {
+    pub power: MealPower,
+    pub pokemon_type: Option<&'static str>,
+    pub level: i64,
+}
+
+impl PowerTarget {
+    fn is_met_by(&self, result: &MealPowerResult) -> bool {
+        result.power == self.power
+            && result.level >= self.level
+            && (!self.power.is_typed()
+                || self.pokemon_type.is_none()
+                || result.pokemon_type == self.pokemon_type)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Ranking {
+    #[default]
+    Ingredients,
+    HerbaMystica,
+}
+
+impl Ranking {
+    pub const ALL: [Ranking; 2] = [Ranking::Ingredients, Ranking::HerbaMystica];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Ranking::Ingredients => "Fewest ingredients",
+            Ranking::HerbaMystica => "Fewest herba mystica",
+        }
+    }
+}
+
+/// Sparkling, title and encounter powers at level 3 for a type.
+pub fn sparkling_targets(pokemon_type: &str) -> Vec<PowerTarget> {
+    let pokemon_type = TYPES.iter().find(|t| **t == pokemon_type).copied();
+    [MealPower::Sparkling, MealPower::Title, MealPower::Encounter]
+        .into_iter()
+        .map(|power| PowerTarget {
+            power,
+            pokemon_type,
+            level: 3,
+        })
+        .collect()
+}
+
+/// Finds up to `limit` sandwiches granting every target power, optionally
+/// limited to what can be made from an inventory.
+///
+/// Only ingredients contributing a targeted power or type are considered, and
+/// herba mystica only when a target needs them. Sandwiches are tried smallest
+/// first, stopping once there are enough recipes for the ranking, then ranked.
+pub fn find_recipes(
+    targets: &[PowerTarget],
+    ranking: Ranking,
+    limit: usize,
+    inventory: Option<&Inventory>,
+) -> Vec<Sandwich> {
+    search(targets, ranking, limit, inventory, MAX_EVALUATIONS)
+}
+
+/// `find_recipes`, giving up with what was found so far once
+/// `max_evaluations` sandwiches and ingredient combinations have been made.
+fn search(
+    targets: &[PowerTarget],
+    ranking: Ranking,
+    limit: usize,
+    inventory: Option<&Inventory>,
+    max_evaluations: usize,
+) -> Vec<Sandwich> {
+    let available = |name: &str| match inventory {
+        Some(inventory) => inventory.get(name).copied().unwrap_or_default(),
+        None => i64::MAX,
+    };
+    let needs_herba = targets
+        .iter()
+        .any(|t| t.level == 3 || t.power == MealPower::Sparkling || t.power == MealPower::Title);
+    // Sparkling power takes two herba mystica and title power one.
+    let fewest_herba = targets
+        .iter()
+        .map(|t| match t.power {
+            MealPower::Sparkling => 2,
+            MealPower::Title => 1,
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default();
+    let relevant = |ingredient: &Ingredient| {
+        if ingredient.is_herba_mystica() {
+            return needs_herba;
+        }
+        targets.iter().any(|t| {
+            ingredient.powers.iter().any(|(p, _)| *p == t.power)
+                || ingredient
+                    .types
+                    .iter()
+                    .any(|(pokemon_type, _)| Some(*pokemon_type) == t.pokemon_type)
+        })
+    };
+    let candidates = |kind| {
+        ingredient_names(kind)
+            .into_iter()
+            .filter(|name| available(name) > 0 && relevant(INGREDIENTS.get(name).unwrap()))
+            .collect::<Vec<_>>()
+    };
+    let fillings = candidates(IngredientKind::Filling);
+    let seasonings = candidates(IngredientKind::Seasoning);
+
+    // Combinations of each size are only made once a sandwich needs them,
+    // and count against the same budget as the sandwiches tried.
+    let mut budget = max_evaluations;
+    let mut filling_sets = vec![None; MAX_FILLINGS];
+    let mut seasoning_sets = vec![None; MAX_SEASONINGS];
+
+    let mut found = Vec::new();
+    'sizes: for size in 2..=MAX_FILLINGS + MAX_SEASONINGS {
+        for filling_count in 1..=MAX_FILLINGS.min(size - 1) {
+            let seasoning_count = size - filling_count;
+            if seasoning_count > MAX_SEASONINGS {
+                continue;
+            }
+            let filling_sets = filling_sets[filling_count - 1].get_or_insert_with(|| {
+                multisets(&fillings, filling_count, &available, &mut budget)
+            });
+            let seasoning_sets = seasoning_sets[seasoning_count - 1].get_or_insert_with(|| {
+                multisets(&seasonings, seasoning_count, &available, &mut budget)
+            });
+            for (filling_names, filling_totals) in filling_sets.iter() {
+                for (seasoning_names, seasoning_totals) in seasoning_sets.iter() {
+                    if budget == 0 {
+                        break 'sizes;
+                    }
+                    budget -= 1;
+                    let powers = filling_totals.combine(seasoning_totals).meal_powers();
+                    if targets
+                        .iter()
+                        .all(|t| powers.iter().any(|p| t.is_met_by(p)))
+                    {
+                        found.push(Sandwich {
+                            fillings: filling_names.iter().map(|n| n.to_string()).collect(),
+                            seasonings: seasoning_names.iter().map(|n| n.to_string()).collect(),
+                        });
+                    }
+                }
+            }
+        }
+        // Larger sandwiches can still use fewer herba mystica, so keep going
+        // until enough use as few as possible.
+        let enough = match ranking {
+            Ranking::Ingredients => found.len(),
+            Ranking::HerbaMystica => found
+                .iter()
+                .filter(|s| s.herba_mystica() <= fewest_herba)
+                .count(),
+        };
+        if enough >= limit {
+            break;
+        }
+    }
+
+    found.sort_by_key(|s| {
+        let count = s.fillings.len() + s.seasonings.len();
+        match ranking {
+            Ranking::Ingredients => (count, s.herba_mystica()),
+            Ranking::HerbaMystica => (s.herba_mystica(), count),
+        }
+    });
+    found.truncate(limit);
+    found
+}
+
+type Combination = (Vec<&'static str>, Totals);
+
+/// Every multiset of `size` names using at most `available(name)` of each,
+/// with the totals of their ingredients. Each one made takes one from
+/// `budget`, stopping early when it runs out.
+fn multisets(
+    names: &[&'static str],
+    size: usize,
+    available: &dyn Fn(&str) -> i64,
+    budget: &mut usize,
+) -> Vec<Combination> {
+    fn go(
+        names: &[&'static str],
+        size: usize,
+        available: &dyn Fn(&str) -> i64,
+        current: &mut Vec<&'static str>,
+        totals: Totals,
+        out: &mut Vec<Combination>,
+        budget: &mut usize,
+    ) {
+        if *budget == 0 {
+            return;
+        }
+        if current.len() == size {
+            *budget -= 1;
+            out.push((current.clone(), totals));
+            return;
+        }
+        // Only names from the last one picked on, so each multiset comes up once.
+        for i in 0..names.len() {
+            let used = current.iter().filter(|n| **n == names[i]).count() as i64;
+            if used >= available(names[i]) {
+                continue;
+            }
+            let mut next = totals;
+            next.add(INGREDIENTS.get(names[i]).unwrap());
+            current.push(names[i]);
+            go(&names[i..], size, available, current, next, out, budget);
+            current.pop();
+        }
+    }
+
+    let mut out = Vec::new();
+    go(
+        names,
+        size,
+        available,
+        &mut Vec::new(),
+        Totals::default(),
+        &mut out,
+        budget,
+    );
+    out
+}
+
+#[component]
+pub fn RecipeFinder(
+    pokemon_type: ReadOnlySignal<String>,
+    inventory: ReadOnlySignal<Inventory>,
+) -> Element {
+    let mut targets = use_signal(|| sparkling_targets(&pokemon_type()));
+    let mut ranking = use_signal(Ranking::default);
+    let mut recipes = use_signal(Vec::<Sandwich>::new);
+    let mut searched = use_signal(|| false);
+    let mut only_owned = use_signal(|| false);
+
+    rsx! {
+        for (i, target) in targets().into_iter().enumerate() {
+            p {
+                select {
+                    oninput: move |e| {
+                        if let Some(power) = MealPower::ALL.into_iter().find(|p| p.label() == e.data.value()) {
+                            targets.write()[i].power = power;
+                        }
+                    },
+                    for power in MealPower::ALL {
+                        option { value: power.label(), selected: power == target.power, "{power.label()}" }
+                    }
+                }
+                select {
+                    oninput: move |e| {
+                        targets.write()[i].pokemon_type = TYPES.iter().find(|t| **t == e.data.value()).copied();
+                    },
+                    option { value: "", selected: target.pokemon_type.is_none(), "any type" }
+                    for t in TYPES.iter() {
+                        option { value: *t, selected: target.pokemon_type == Some(*t), "{t}" }
+                    }
+                }
+                select {
+                    oninput: move |e| {
+                        targets.write()[i].level = e.data.value().parse::<i64>().unwrap_or(1);
+                    },
+                    for level in [1, 2, 3] {
+                        option { value: "{level}", selected: level == target.level, "Lv. {level}" }
+                    }
+                }
+                button {
+                    style: "margin-left: 10px;",
+                    onclick: move |_| {
+                        targets.write().remove(i);
+                    },
+                    "✕"
+                }
+            }
+        }
+        p {
+            if targets.read().len() < 3 {
+                button {
+                    style: "margin-right: 10px;",
+                    onclick: move |_| {
+                        targets.write().push(PowerTarget {
+                            power: MealPower::Encounter,
+                            pokemon_type: None,
+                            level: 1,
+                        });
+                    },
+                    "Add power"
+                }
+            }
+            select {
+                oninput: move |e| {
+                    ranking.set(Ranking::ALL.into_iter().find(|r| r.label() == e.data.value()).unwrap_or_default());
+                },
+                for r in Ranking::ALL {
+                    option { value: r.label(), selected: r == ranking(), "{r.label()}" }
+                }
+            }
+            label { style: "margin-left: 10px;",
+                input {
+                    r#type: "checkbox",
+                    oninput: move |_| only_owned.set(!only_owned()),
+                    checked: only_owned()
+                }
+                " Only ingredients I have"
+            }
+            button {
+                style: "margin-left: 10px;",
+                onclick: move |_| {
+                    let inventory = inventory.read();
+                    let inventory = only_owned().then_some(&*inventory);
+                    recipes.set(find_recipes(&targets(), ranking(), 5, inventory));
+                    searched.set(true);
+                },
+                "Find recipes"
+            }
+        }
+        if searched() && recipes.read().is_empty() {
+            p { "No recipe found for those powers" }
+        }
+        for recipe in recipes() {
+            p { b { "{recipe}" } }
+            SandwichResult { sandwich: recipe.clone() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_one_filling_sparkling_recipe() {
+        let recipes = find_recipes(&sparkling_targets("dragon"), Ranking::Ingredients, 3, None);
+
+        assert!(!recipes.is_empty());
+        assert_eq!(recipes[0].fillings.len() + recipes[0].seasonings.len(), 3);
+        assert_eq!(recipes[0].herba_mystica(), 2);
+        for recipe in &recipes {
+            assert_eq!(recipe.sparkling_level(), 3);
+            assert!(recipe
+                .powers()
+                .iter()
+                .any(|p| p.power == MealPower::Encounter
+                    && p.pokemon_type == Some("dragon")
+                    && p.level == 3));
+        }
+    }
+
+    #[test]
+    fn only_uses_what_the_inventory_has() {
+        let inventory = Inventory::from([
+            ("avocado".to_string(), 1),
+            ("salty herba mystica".to_string(), 1),
+            ("spicy herba mystica".to_string(), 1),
+        ]);
+
+        let recipes = find_recipes(
+            &sparkling_targets("dragon"),
+            Ranking::Ingredients,
+            10,
+            Some(&inventory),
+        );
+
+        assert_eq!(
+            recipes,
+            vec![Sandwich {
+                fillings: vec!["avocado".to_string()],
+                seasonings: vec![
+                    "salty herba mystica".to_string(),
+                    "spicy herba mystica".to_string()
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn hitting_the_cap_returns_what_was_found() {
+        let targets = sparkling_targets("dragon");
+        let all = search(&targets, Ranking::Ingredients, usize::MAX, None, 5_000);
+
+        assert!(!all.is_empty());
+        assert!(all.len() < search(&targets, Ranking::Ingredients, usize::MAX, None, 50_000).len());
+    }
+
+    #[test]
+    fn ranking_by_herba_looks_past_the_smallest_recipes() {
+        let targets = [PowerTarget {
+            power: MealPower::Encounter,
+            pokemon_type: Some("dragon"),
+            level: 3,
+        }];
+        let inventory = Inventory::from([
+            ("avocado".to_string(), 6),
+            ("peanut butter".to_string(), 3),
+            ("salty herba mystica".to_string(), 2),
+        ]);
+
+        let smallest = find_recipes(&targets, Ranking::Ingredients, 1, Some(&inventory));
+        let fewest_herba = find_recipes(&targets, Ranking::HerbaMystica, 1, Some(&inventory));
+
+        assert_eq!(smallest[0].herba_mystica(), 2);
+        assert_eq!(fewest_herba[0].herba_mystica(), 1);
+        assert!(fewest_herba[0].fillings.len() > smallest[0].fillings.len());
+    }
+
+    #[test]
+    fn combinations_count_against_the_budget() {
+        let fillings = ingredient_names(IngredientKind::Filling);
+        let mut budget = 1_000;
+
+        let sets = multisets(&fillings, MAX_FILLINGS, &|_| i64::MAX, &mut budget);
+
+        assert_eq!(sets.len(), 1_000);
+        assert_eq!(budget, 0);
+    }
+
+    #[test]
+    fn broad_searches_stop_at_the_cap() {
+        // Every ingredient is relevant, which is millions of six filling combinations.
+        let targets = MealPower::ALL
+            .into_iter()
+            .map(|power| PowerTarget {
+                power,
+                pokemon_type: None,
+                level: 1,
+            })
+            .collect::<Vec<_>>();
+        let start = std::time::Instant::now();
+
+        search(&targets, Ranking::HerbaMystica, 5, None, 10_000);
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,914 @@
+use dioxus::prelude::*;
+use phf::phf_map;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::shiny_dex::shiny_dex::TYPES;
+use crate::shiny_dex::TYPES_INFO;
+
+/// Single player sandwiches take up to 6 fillings and 4 seasonings.
+pub const MAX_FILLINGS: usize = 6;
+pub const MAX_SEASONINGS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Flavor {
+    Sweet,
+    Salty,
+    Sour,
+    Bitter,
+    Hot,
+}
+
+impl Flavor {
+    pub const ALL: [Flavor; 5] = [
+        Flavor::Sweet,
+        Flavor::Salty,
+        Flavor::Sour,
+        Flavor::Bitter,
+        Flavor::Hot,
+    ];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MealPower {
+    Egg,
+    Catching,
+    Exp,
+    ItemDrop,
+    Raid,
+    Title,
+    Humungo,
+    Teensy,
+    Encounter,
+    Sparkling,
+}
+
+impl MealPower {
+    pub const ALL: [MealPower; 10] = [
+        MealPower::Egg,
+        MealPower::Catching,
+        MealPower::Exp,
+        MealPower::ItemDrop,
+        MealPower::Raid,
+        MealPower::Title,
+        MealPower::Humungo,
+        MealPower::Teensy,
+        MealPower::Encounter,
+        MealPower::Sparkling,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MealPower::Egg => "Egg",
+            MealPower::Catching => "Catching",
+            MealPower::Exp => "Exp. Point",
+            MealPower::ItemDrop => "Item Drop",
+            MealPower::Raid => "Raid",
+            MealPower::Title => "Title",
+            MealPower::Humungo => "Humungo",
+            MealPower::Teensy => "Teensy",
+            MealPower::Encounter => "Encounter",
+            MealPower::Sparkling => "Sparkling",
+        }
+    }
+
+    /// Egg power is the only power that isn't tied to a type.
+    pub fn is_typed(&self) -> bool {
+        *self != MealPower::Egg
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IngredientKind {
+    Filling,
+    Seasoning,
+}
+
+/// Contributions of a full portion of an ingredient to a sandwich.
+pub struct Ingredient {
+    pub kind: IngredientKind,
+    pub flavors: &'static [(Flavor, i64)],
+    pub powers: &'static [(MealPower, i64)],
+    pub types: &'static [(&'static str, i64)],
+}
+
+impl Ingredient {
+    pub fn is_herba_mystica(&self) -> bool {
+        self.powers
+            .iter()
+            .any(|(power, _)| *power == MealPower::Sparkling)
+    }
+}
+
+static HERBA_TYPES: [(&str, i64); 18] = [
+    ("normal", 250),
+    ("grass", 250),
+    ("fire", 250),
+    ("water", 250),
+    ("electric", 250),
+    ("ice", 250),
+    ("fighting", 250),
+    ("poison", 250),
+    ("ground", 250),
+    ("flying", 250),
+    ("psychic", 250),
+    ("bug", 250),
+    ("rock", 250),
+    ("ghost", 250),
+    ("dragon", 250),
+    ("dark", 250),
+    ("steel", 250),
+    ("fairy", 250),
+];
+
+static HERBA_POWERS: [(MealPower, i64); 2] =
+    [(MealPower::Sparkling, 1000), (MealPower::Title, 1000)];
+
+pub static INGREDIENTS: phf::Map<&'static str, Ingredient> = phf_map! {
+    "tofu" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 4), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Exp, 12)],
+        types: &[("normal", 36)],
+    },
+    "lettuce" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Bitter, 12)],
+        powers: &[(MealPower::ItemDrop, 12)],
+        types: &[("grass", 36)],
+    },
+    "red pepper" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Hot, 8), (Flavor::Sweet, 4)],
+        powers: &[(MealPower::Raid, 12)],
+        types: &[("fire", 36)],
+    },
+    "cucumber" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sour, 4), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Teensy, 12)],
+        types: &[("water", 36)],
+    },
+    "yellow pepper" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Bitter, 4)],
+        powers: &[(MealPower::Catching, 12)],
+        types: &[("electric", 36)],
+    },
+    "klawf stick" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::Exp, 12)],
+        types: &[("ice", 36)],
+    },
+    "pickle" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sour, 12)],
+        powers: &[(MealPower::Teensy, 12)],
+        types: &[("fighting", 36)],
+    },
+    "green pepper" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Bitter, 12)],
+        powers: &[(MealPower::Encounter, 35)],
+        types: &[("poison", 36)],
+    },
+    "ham" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::Humungo, 12)],
+        types: &[("ground", 36)],
+    },
+    "prosciutto" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::Raid, 12)],
+        types: &[("flying", 36)],
+    },
+    "onion" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Hot, 4), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Encounter, 35)],
+        types: &[("psychic", 36)],
+    },
+    "cherry tomato" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 4), (Flavor::Sour, 8)],
+        powers: &[(MealPower::Catching, 12)],
+        types: &[("bug", 36)],
+    },
+    "bacon" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::Egg, 12)],
+        types: &[("rock", 36)],
+    },
+    "red onion" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Hot, 8), (Flavor::Bitter, 4)],
+        powers: &[(MealPower::Teensy, 12)],
+        types: &[("ghost", 36)],
+    },
+    "avocado" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 4), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Humungo, 12)],
+        types: &[("dragon", 36)],
+    },
+    "smoked fillet" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::ItemDrop, 12)],
+        types: &[("dark", 36)],
+    },
+    "hamburger" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 8), (Flavor::Hot, 4)],
+        powers: &[(MealPower::Encounter, 35)],
+        types: &[("steel", 36)],
+    },
+    "tomato" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 4), (Flavor::Sour, 8)],
+        powers: &[(MealPower::Encounter, 35)],
+        types: &[("fairy", 36)],
+    },
+    "cheese" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 8), (Flavor::Sour, 4)],
+        powers: &[(MealPower::Egg, 21)],
+        types: &[("normal", 12), ("fairy", 12)],
+    },
+    "egg" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Salty, 4)],
+        powers: &[(MealPower::Egg, 21)],
+        types: &[("normal", 12), ("fighting", 12)],
+    },
+    "banana" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 12)],
+        powers: &[(MealPower::Egg, 21)],
+        types: &[("grass", 12), ("electric", 12)],
+    },
+    "apple" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Sour, 4)],
+        powers: &[(MealPower::Egg, 21)],
+        types: &[("grass", 12), ("bug", 12)],
+    },
+    "jalapeno" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Hot, 12)],
+        powers: &[(MealPower::Raid, 21)],
+        types: &[("fire", 12), ("ground", 12)],
+    },
+    "watercress" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Bitter, 8), (Flavor::Hot, 4)],
+        powers: &[(MealPower::Humungo, 21)],
+        types: &[("water", 12), ("grass", 12)],
+    },
+    "basil" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Bitter, 8), (Flavor::Sweet, 4)],
+        powers: &[(MealPower::Catching, 21)],
+        types: &[("grass", 12), ("psychic", 12)],
+    },
+    "kiwi" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sour, 8), (Flavor::Sweet, 4)],
+        powers: &[(MealPower::Teensy, 21)],
+        types: &[("grass", 12), ("electric", 12)],
+    },
+    "pineapple" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Sour, 4)],
+        powers: &[(MealPower::ItemDrop, 21)],
+        types: &[("electric", 12), ("water", 12)],
+    },
+    "strawberry" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Sour, 4)],
+        powers: &[(MealPower::Catching, 21)],
+        types: &[("fairy", 12), ("bug", 12)],
+    },
+    "chorizo" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Hot, 8), (Flavor::Salty, 4)],
+        powers: &[(MealPower::Raid, 21)],
+        types: &[("fire", 12), ("dark", 12)],
+    },
+    "herbed sausage" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 8), (Flavor::Bitter, 4)],
+        powers: &[(MealPower::Exp, 21)],
+        types: &[("flying", 12), ("dragon", 12)],
+    },
+    "fried fillet" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 12)],
+        powers: &[(MealPower::Humungo, 21)],
+        types: &[("water", 12), ("rock", 12)],
+    },
+    "potato salad" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 8), (Flavor::Sour, 4)],
+        powers: &[(MealPower::Catching, 21)],
+        types: &[("ground", 12), ("ice", 12)],
+    },
+    "rice" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Sweet, 8), (Flavor::Salty, 4)],
+        powers: &[(MealPower::Exp, 21)],
+        types: &[("normal", 12), ("ghost", 12)],
+    },
+    "noodles" => Ingredient {
+        kind: IngredientKind::Filling,
+        flavors: &[(Flavor::Salty, 8), (Flavor::Sweet, 4)],
+        powers: &[(MealPower::Raid, 21)],
+        types: &[("steel", 12), ("dragon", 12)],
+    },
+    "salt" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Salty, 20)],
+        powers: &[(MealPower::Encounter, 7)],
+        types: &[("normal", 2), ("ice", 2)],
+    },
+    "pepper" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 20)],
+        powers: &[(MealPower::Raid, 7)],
+        types: &[("fire", 2), ("dark", 2)],
+    },
+    "mayonnaise" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sour, 12), (Flavor::Salty, 8)],
+        powers: &[(MealPower::Egg, 7)],
+        types: &[("normal", 2), ("poison", 2)],
+    },
+    "ketchup" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 12), (Flavor::Sour, 8)],
+        powers: &[(MealPower::Encounter, 7)],
+        types: &[("fire", 2), ("fairy", 2)],
+    },
+    "mustard" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 12), (Flavor::Sour, 8)],
+        powers: &[(MealPower::Catching, 7)],
+        types: &[("electric", 2), ("fighting", 2)],
+    },
+    "butter" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 12), (Flavor::Salty, 8)],
+        powers: &[(MealPower::Egg, 7)],
+        types: &[("normal", 2), ("steel", 2)],
+    },
+    "peanut butter" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 12), (Flavor::Salty, 8)],
+        powers: &[(MealPower::ItemDrop, 7)],
+        types: &[("fighting", 2), ("dragon", 2)],
+    },
+    "jam" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 20)],
+        powers: &[(MealPower::Egg, 7)],
+        types: &[("fairy", 2), ("bug", 2)],
+    },
+    "whipped cream" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 20)],
+        powers: &[(MealPower::Egg, 7)],
+        types: &[("normal", 2), ("ice", 2)],
+    },
+    "yogurt" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sour, 12), (Flavor::Sweet, 8)],
+        powers: &[(MealPower::Raid, 7)],
+        types: &[("ice", 2), ("fairy", 2)],
+    },
+    "vinegar" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sour, 20)],
+        powers: &[(MealPower::Teensy, 7)],
+        types: &[("poison", 2), ("water", 2)],
+    },
+    "olive oil" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Bitter, 12), (Flavor::Salty, 8)],
+        powers: &[(MealPower::Humungo, 7)],
+        types: &[("grass", 2), ("flying", 2)],
+    },
+    "chili sauce" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 12), (Flavor::Sweet, 8)],
+        powers: &[(MealPower::Raid, 7)],
+        types: &[("fire", 2), ("ghost", 2)],
+    },
+    "curry powder" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 12), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Exp, 7)],
+        types: &[("fire", 2), ("ground", 2)],
+    },
+    "horseradish" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 12), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Humungo, 7)],
+        types: &[("psychic", 2), ("rock", 2)],
+    },
+    "wasabi" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 12), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Teensy, 7)],
+        types: &[("grass", 2), ("dragon", 2)],
+    },
+    "cream cheese" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Salty, 12), (Flavor::Sour, 8)],
+        powers: &[(MealPower::ItemDrop, 7)],
+        types: &[("normal", 2), ("water", 2)],
+    },
+    "marmalade" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 12), (Flavor::Bitter, 8)],
+        powers: &[(MealPower::Teensy, 7)],
+        types: &[("bug", 2), ("dark", 2)],
+    },
+    "sweet herba mystica" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sweet, 500)],
+        powers: &HERBA_POWERS,
+        types: &HERBA_TYPES,
+    },
+    "salty herba mystica" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Salty, 500)],
+        powers: &HERBA_POWERS,
+        types: &HERBA_TYPES,
+    },
+    "sour herba mystica" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Sour, 500)],
+        powers: &HERBA_POWERS,
+        types: &HERBA_TYPES,
+    },
+    "bitter herba mystica" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Bitter, 500)],
+        powers: &HERBA_POWERS,
+        types: &HERBA_TYPES,
+    },
+    "spicy herba mystica" => Ingredient {
+        kind: IngredientKind::Seasoning,
+        flavors: &[(Flavor::Hot, 500)],
+        powers: &HERBA_POWERS,
+        types: &HERBA_TYPES,
+    },
+};
+
+/// Names of every ingredient of the given kind, alphabetically.
+pub fn ingredient_names(kind: IngredientKind) -> Vec<&'static str> {
+    let mut names = INGREDIENTS
+        .entries()
+        .filter(|(_, ingredient)| ingredient.kind == kind)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// The power that gets a +100 boost from the sandwich's two strongest flavors.
+fn flavor_boost(first: Flavor, second: Flavor) -> MealPower {
+    match (first, second) {
+        (Flavor::Sweet, Flavor::Sour) | (Flavor::Sour, Flavor::Sweet) => MealPower::Catching,
+        (Flavor::Salty, Flavor::Bitter) | (Flavor::Bitter, Flavor::Salty) => MealPower::Exp,
+        (Flavor::Sweet, Flavor::Hot) | (Flavor::Hot, Flavor::Sweet) => MealPower::Raid,
+        (Flavor::Sweet, _) => MealPower::Egg,
+        (Flavor::Salty, _) => MealPower::Encounter,
+        (Flavor::Sour, _) => MealPower::Teensy,
+        (Flavor::Bitter, _) => MealPower::ItemDrop,
+        (Flavor::Hot, _) => MealPower::Humungo,
+    }
+}
+
+/// Levels of the three meal powers, driven by the strongest types.
+fn levels(first: i64, second: i64, third: i64) -> [i64; 3] {
+    if first >= 460 {
+        [3, 3, 3]
+    } else if first > 280 {
+        if third >= 180 {
+            [2, 2, 2]
+        } else {
+            [2, 2, 1]
+        }
+    } else if first > 180 {
+        if second >= 180 && third >= 180 {
+            [2, 2, 1]
+        } else {
+            [2, 1, 1]
+        }
+    } else {
+        [1, 1, 1]
+    }
+}
+
+/// Summed flavor, power and type contributions of a sandwich's ingredients.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Totals {
+    flavors: [i64; 5],
+    powers: [i64; 10],
+    types: [i64; 18],
+}
+
+impl Totals {
+    pub fn add(&mut self, ingredient: &Ingredient) {
+        for (flavor, amount) in ingredient.flavors {
+            self.flavors[*flavor as usize] += amount;
+        }
+        for (power, amount) in ingredient.powers {
+            self.powers[*power as usize] += amount;
+        }
+        for (pokemon_type, amount) in ingredient.types {
+            if let Some(i) = TYPES.iter().position(|t| t == pokemon_type) {
+                self.types[i] += amount;
+            }
+        }
+    }
+
+    pub fn combine(&self, other: &Totals) -> Totals {
+        let mut totals = *self;
+        totals
+            .flavors
+            .iter_mut()
+            .zip(other.flavors)
+            .for_each(|(a, b)| *a += b);
+        totals
+            .powers
+            .iter_mut()
+            .zip(other.powers)
+            .for_each(|(a, b)| *a += b);
+        totals
+            .types
+            .iter_mut()
+            .zip(other.types)
+            .for_each(|(a, b)| *a += b);
+        totals
+    }
+
+    /// The meal powers these totals grant, strongest first.
+    pub fn meal_powers(&self) -> Vec<MealPowerResult> {
+        let mut powers = self.powers;
+        let types = self.types;
+
+        // Ties are broken by declaration order, as the game does.
+        let mut flavor_order = Flavor::ALL;
+        flavor_order.sort_by_key(|f| std::cmp::Reverse(self.flavors[*f as usize]));
+        powers[flavor_boost(flavor_order[0], flavor_order[1]) as usize] += 100;
+
+        let mut type_order = (0..TYPES.len()).collect::<Vec<_>>();
+        type_order.sort_by_key(|t| std::cmp::Reverse(types[*t]));
+        let [first, second, third] = [0, 1, 2].map(|i| types[type_order[i]]);
+        let levels = levels(first, second, third);
+        // A dominant type takes over every power, otherwise powers get the 1st, 3rd and 2nd types.
+        let power_types = if first > 480 || first - second > 105 {
+            [type_order[0]; 3]
+        } else {
+            [type_order[0], type_order[2], type_order[1]]
+        };
+
+        // Sparkling power needs two herba mystica, and always comes first.
+        let sparkling = powers[MealPower::Sparkling as usize] >= 2000;
+        let mut power_order = MealPower::ALL
+            .into_iter()
+            .filter(|p| powers[*p as usize] > 0)
+            .filter(|p| *p != MealPower::Sparkling || sparkling)
+            .collect::<Vec<_>>();
+        power_order.sort_by_key(|p| {
+            (
+                *p != MealPower::Sparkling,
+                std::cmp::Reverse(powers[*p as usize]),
+            )
+        });
+
+        power_order
+            .into_iter()
+            .take(3)
+            .enumerate()
+            .map(|(i, power)| MealPowerResult {
+                power,
+                pokemon_type: power.is_typed().then(|| TYPES[power_types[i]]),
+                level: levels[i],
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MealPowerResult {
+    pub power: MealPower,
+    pub pokemon_type: Option<&'static str>,
+    pub level: i64,
+}
+
+impl fmt::Display for MealPowerResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pokemon_type {
+            Some(t) => write!(f, "{} Power: {} Lv. {}", self.power.label(), t, self.level),
+            None => write!(f, "{} Power Lv. {}", self.power.label(), self.level),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sandwich {
+    pub fillings: Vec<String>,
+    pub seasonings: Vec<String>,
+}
+
+impl Sandwich {
+    /// The tomato/onion/green pepper/hamburger base with two of the type's
+    /// ingredient and two herba mystica, for a 3 star sparkling/title/encounter sandwich.
+    pub fn sparkling(pokemon_type: &str) -> Self {
+        let ingredient = TYPES_INFO.get(pokemon_type).unwrap().ingredient.to_string();
+        Self {
+            fillings: vec![
+                "tomato".to_string(),
+                "onion".to_string(),
+                "green pepper".to_string(),
+                "hamburger".to_string(),
+                ingredient.clone(),
+                ingredient,
+            ],
+            seasonings: vec![
+                "salty herba mystica".to_string(),
+                "spicy herba mystica".to_string(),
+            ],
+        }
+    }
+
+    pub fn list(&self, kind: IngredientKind) -> &Vec<String> {
+        match kind {
+            IngredientKind::Filling => &self.fillings,
+            IngredientKind::Seasoning => &self.seasonings,
+        }
+    }
+
+    pub fn list_mut(&mut self, kind: IngredientKind) -> &mut Vec<String> {
+        match kind {
+            IngredientKind::Filling => &mut self.fillings,
+            IngredientKind::Seasoning => &mut self.seasonings,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.fillings.is_empty()
+            && !self.seasonings.is_empty()
+            && self.fillings.len() <= MAX_FILLINGS
+            && self.seasonings.len() <= MAX_SEASONINGS
+            && self.ingredients().all(|(_, i)| i.is_some())
+    }
+
+    fn ingredients(&self) -> impl Iterator<Item = (&String, Option<&'static Ingredient>)> {
+        self.fillings
+            .iter()
+            .chain(self.seasonings.iter())
+            .map(|name| (name, INGREDIENTS.get(name.as_str())))
+    }
+
+    /// Number of herba mystica used, the scarce part of most recipes.
+    pub fn herba_mystica(&self) -> usize {
+        self.ingredients()
+            .filter(|(_, i)| i.is_some_and(|i| i.is_herba_mystica()))
+            .count()
+    }
+
+    /// The meal powers this sandwich grants, strongest first.
+    pub fn powers(&self) -> Vec<MealPowerResult> {
+        if !self.is_valid() {
+            return vec![];
+        }
+
+        let mut totals = Totals::default();
+        for (_, ingredient) in self.ingredients() {
+            totals.add(ingredient.unwrap());
+        }
+        totals.meal_powers()
+    }
+
+    /// The sparkling power level, which is what the hunt odds care about.
+    pub fn sparkling_level(&self) -> i64 {
+        self.powers()
+            .iter()
+            .find(|p| p.power == MealPower::Sparkling)
+            .map(|p| p.level)
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Sandwich {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::<String>::new();
+        for list in [&self.fillings, &self.seasonings] {
+            let mut seen = Vec::<&String>::new();
+            for name in list.iter() {
+                if seen.contains(&name) {
+                    continue;
+                }
+                seen.push(name);
+                match list.iter().filter(|n| *n == name).count() {
+                    1 => parts.push(name.clone()),
+                    n => parts.push(format!("{n} * {name}")),
+                }
+            }
+        }
+        write!(f, "{}", parts.join(" + "))
+    }
+}
+
+#[component]
+pub fn SandwichResult(sandwich: ReadOnlySignal<Sandwich>) -> Element {
+    let powers = sandwich.read().powers();
+    let sparkling_level = sandwich.read().sparkling_level();
+    let herba_mystica = sandwich.read().herba_mystica();
+
+    rsx! {
+        if powers.is_empty() {
+            p { "Add at least one filling and one seasoning" }
+        }
+        ul {
+            for power in powers {
+                li { "{power}" }
+            }
+        }
+        if sparkling_level > 0 {
+            p { "+{sparkling_level} shiny rolls, using {herba_mystica} herba mystica" }
+        }
+    }
+}
+
+#[component]
+pub fn SandwichBuilder(sandwich: Signal<Sandwich>) -> Element {
+    rsx! {
+        IngredientPicker {
+            sandwich,
+            kind: IngredientKind::Filling,
+            label: "Fillings",
+            limit: MAX_FILLINGS,
+        }
+        IngredientPicker {
+            sandwich,
+            kind: IngredientKind::Seasoning,
+            label: "Seasonings",
+            limit: MAX_SEASONINGS,
+        }
+        SandwichResult { sandwich }
+    }
+}
+
+#[component]
+fn IngredientPicker(
+    sandwich: Signal<Sandwich>,
+    kind: IngredientKind,
+    label: &'static str,
+    limit: usize,
+) -> Element {
+    let chosen = sandwich.read().list(kind).clone();
+
+    rsx! {
+        p {
+            "{label} ({chosen.len()}/{limit}): ",
+            for (i, name) in chosen.into_iter().enumerate() {
+                button {
+                    style: "margin-right: 5px;",
+                    title: "Remove",
+                    onclick: move |_| {
+                        sandwich.write().list_mut(kind).remove(i);
+                    },
+                    "{name} ✕"
+                }
+            }
+            select {
+                oninput: move |e| {
+                    let name = e.data.value();
+                    let mut sandwich = sandwich.write();
+                    let list = sandwich.list_mut(kind);
+                    if !name.is_empty() && list.len() < limit {
+                        list.push(name);
+                    }
+                },
+                option { value: "", selected: true, "add..." }
+                for name in ingredient_names(kind) {
+                    option { value: name, "{name}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sandwich(fillings: &[&str], seasonings: &[&str]) -> Sandwich {
+        Sandwich {
+            fillings: fillings.iter().map(|f| f.to_string()).collect(),
+            seasonings: seasonings.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn result(power: MealPower, pokemon_type: Option<&'static str>, level: i64) -> MealPowerResult {
+        MealPowerResult {
+            power,
+            pokemon_type,
+            level,
+        }
+    }
+
+    #[test]
+    fn double_herba_recipe_is_sparkling_title_encounter_three() {
+        let powers = Sandwich::sparkling("ghost").powers();
+
+        assert_eq!(
+            powers,
+            vec![
+                result(MealPower::Sparkling, Some("ghost"), 3),
+                result(MealPower::Title, Some("ghost"), 3),
+                result(MealPower::Encounter, Some("ghost"), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_filling_with_salty_and_spicy_herba_is_enough() {
+        let powers = sandwich(
+            &["avocado"],
+            &["salty herba mystica", "spicy herba mystica"],
+        )
+        .powers();
+
+        assert_eq!(
+            powers,
+            vec![
+                result(MealPower::Sparkling, Some("dragon"), 3),
+                result(MealPower::Title, Some("dragon"), 3),
+                result(MealPower::Encounter, Some("dragon"), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_herba_mystica_is_not_sparkling() {
+        let sandwich = sandwich(&["avocado"], &["salty herba mystica"]);
+
+        assert_eq!(sandwich.sparkling_level(), 0);
+        assert_eq!(sandwich.powers()[0].power, MealPower::Title);
+    }
+
+    #[test]
+    fn six_hamburgers_and_salt_is_encounter_two() {
+        let powers = sandwich(&["hamburger"; 6], &["salt"]).powers();
+
+        assert_eq!(powers, vec![result(MealPower::Encounter, Some("steel"), 2)]);
+    }
+
+    #[test]
+    fn flavor_boost_takes_pairs_then_the_strongest_flavor() {
+        assert_eq!(
+            flavor_boost(Flavor::Sweet, Flavor::Sour),
+            MealPower::Catching
+        );
+        assert_eq!(
+            flavor_boost(Flavor::Sour, Flavor::Sweet),
+            MealPower::Catching
+        );
+        assert_eq!(flavor_boost(Flavor::Bitter, Flavor::Salty), MealPower::Exp);
+        assert_eq!(flavor_boost(Flavor::Hot, Flavor::Sweet), MealPower::Raid);
+        assert_eq!(
+            flavor_boost(Flavor::Salty, Flavor::Hot),
+            MealPower::Encounter
+        );
+        assert_eq!(flavor_boost(Flavor::Hot, Flavor::Salty), MealPower::Humungo);
+    }
+
+    #[test]
+    fn tied_flavors_break_sweet_salty_sour_bitter_hot() {
+        // Sweet and sour both 20, so the pair is sweet then sour: catching.
+        let powers = sandwich(&["lettuce"], &["jam", "vinegar"]).powers();
+
+        assert_eq!(powers[0].power, MealPower::Catching);
+        assert_eq!(powers[0].level, 1);
+    }
+
+    #[test]
+    fn levels_follow_the_type_thresholds() {
+        assert_eq!(levels(500, 500, 500), [3, 3, 3]);
+        assert_eq!(levels(300, 200, 180), [2, 2, 2]);
+        assert_eq!(levels(300, 200, 100), [2, 2, 1]);
+        assert_eq!(levels(200, 180, 180), [2, 2, 1]);
+        assert_eq!(levels(200, 100, 50), [2, 1, 1]);
+        assert_eq!(levels(180, 100, 50), [1, 1, 1]);
+    }
+}
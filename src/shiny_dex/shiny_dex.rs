@@ -2,10 +2,16 @@ use dioxus::prelude::*;
 
 use crate::footer;
 use crate::shiny_dex::dex_by_type::DexByType;
+use crate::shiny_dex::exclusives::MyGameSelect;
+use crate::shiny_dex::pokedexes::DexSelect;
+use crate::shiny_dex::progress::Progress;
+use crate::shiny_dex::storage::{use_stored, CaughtKey};
+use crate::shiny_dex::TYPES_INFO;
 
 static DEFAULT_DEX: &str = "paldea";
 static DEFAULT_TYPE: &str = "normal";
 
+/// The Scarlet and Violet dexes, tracked by the progress bars and hunt planner.
 pub static DEXES: [&str; 3] = ["paldea", "kitakami", "blueberry"];
 
 pub static TYPES: [&str; 18] = [
@@ -15,37 +21,74 @@ pub static TYPES: [&str; 18] = [
 
 #[component]
 pub fn ShinyDex() -> Element {
-    let mut dex = use_signal(|| DEFAULT_DEX.to_string());
-    let mut pokemon_type = use_signal(|| DEFAULT_TYPE.to_string());
+    let dex = use_signal(|| DEFAULT_DEX.to_string());
+    let mut pokemon_types = use_signal(|| vec![DEFAULT_TYPE.to_string()]);
+    let caught = use_stored::<CaughtKey>();
+    let mut missing_only = use_signal(|| false);
 
     rsx! {
         div { display: "flex", flex_direction: "row", justify_content: "space-between",
             h1 { "Welcome to the shiny hunters pokédex" }
-            h1 { a { href: "/shiny/favourites", "View your favourites" } }
+            h1 {
+                a { href: "/shiny/favourites", "View your favourites" }
+                " | "
+                a { href: "/shiny/inventory", "Sandwich ingredients" }
+                " | "
+                a { href: "/shiny/planner", "Hunt planner" }
+                " | "
+                a { href: "/shiny/outbreaks", "Mass outbreaks" }
+                " | "
+                a { href: "/shiny/backup", "Import/export" }
+                " | "
+                a { href: "/shiny/sync", "Sync" }
+            }
         }
         div { display: "flex", flex_direction: "row",
-            select {
-                class: "bg-white font-bold py-2 px-4 rounded",
-                width: "20%",
-                oninput: move |event| {
-                    dex.set(event.data.value().clone());
-                },
-                for dex in DEXES.iter() {
-                    option { value: *dex, "{dex}" }
+            DexSelect { dex }
+            MyGameSelect {}
+            button {
+                class: "font-bold py-2 px-4 rounded",
+                onclick: move |_| pokemon_types.set(TYPES.iter().map(|t| t.to_string()).collect()),
+                "All types"
+            }
+            label { class: "py-2 px-4",
+                input {
+                    r#type: "checkbox",
+                    oninput: move |_| missing_only.set(!missing_only()),
+                    checked: missing_only()
                 }
+                " Missing only"
             }
-            select {
-                class: "bg-white font-bold py-2 px-4 rounded",
-                width: "20%",
-                oninput: move |event| {
-                    pokemon_type.set(event.data.value().clone());
-                },
-                for key in TYPES.iter() {
-                    option { value: *key, "{key}" }
+        }
+        div { display: "flex", flex_direction: "row", flex_wrap: "wrap",
+            for key in TYPES.iter() {
+                button {
+                    class: "py-1 px-2 rounded",
+                    margin: "2px",
+                    color: "white",
+                    title: "Shift-click to select several types",
+                    background_color: if pokemon_types.read().iter().any(|t| t == key) { TYPES_INFO.get(key).unwrap().color } else { "grey" },
+                    // Shift-click adds or removes a type, a plain click shows just that type.
+                    onclick: move |event| {
+                        if event.modifiers().shift() {
+                            let mut types = pokemon_types.write();
+                            match types.iter().position(|t| t == key) {
+                                Some(i) if types.len() > 1 => {
+                                    types.remove(i);
+                                }
+                                Some(_) => {}
+                                None => types.push(key.to_string()),
+                            }
+                        } else {
+                            pokemon_types.set(vec![key.to_string()]);
+                        }
+                    },
+                    "{key}"
                 }
             }
         }
-        DexByType { dex: dex, pokemon_type: pokemon_type }
+        Progress { dex, caught }
+        DexByType { dex: dex, pokemon_types, caught, missing_only }
         footer::Footer {}
     }
 }
@@ -0,0 +1,153 @@
+use phf::phf_map;
+
+/// Game version the table below was last checked against; bump it when editing the table.
+pub const SHINY_LOCKS_VERSION: &str = "Scarlet/Violet 3.0";
+
+pub struct ShinyLock {
+    /// Whether a shiny can still be obtained some other way, e.g. by breeding.
+    pub obtainable: bool,
+    pub note: &'static str,
+}
+
+const BOX_LEGEND: ShinyLock = ShinyLock {
+    obtainable: false,
+    note: "Box legend, only one is given and it's shiny locked",
+};
+const RUINOUS: ShinyLock = ShinyLock {
+    obtainable: false,
+    note: "Treasure of Ruin, shiny locked",
+};
+const LOYAL_THREE: ShinyLock = ShinyLock {
+    obtainable: false,
+    note: "Loyal Three, shiny locked",
+};
+const PARADOX_LEGEND: ShinyLock = ShinyLock {
+    obtainable: false,
+    note: "Paradox legend, shiny locked",
+};
+const SNACKSWORTH: ShinyLock = ShinyLock {
+    obtainable: false,
+    note: "Snacksworth legend, shiny locked",
+};
+const GIFT_STARTER: ShinyLock = ShinyLock {
+    obtainable: true,
+    note: "The gift is shiny locked, breed one for a shiny",
+};
+
+/// Species and forms with no huntable shiny in Scarlet and Violet, keyed by
+/// pokemon name. Forms not listed fall back to their base name, so
+/// "ogerpon-wellspring-mask" uses "ogerpon".
+static SHINY_LOCKS: phf::Map<&'static str, ShinyLock> = phf_map! {
+    "koraidon" => BOX_LEGEND,
+    "miraidon" => BOX_LEGEND,
+    "wo-chien" => RUINOUS,
+    "chien-pao" => RUINOUS,
+    "ting-lu" => RUINOUS,
+    "chi-yu" => RUINOUS,
+    "okidogi" => LOYAL_THREE,
+    "munkidori" => LOYAL_THREE,
+    "fezandipiti" => LOYAL_THREE,
+    "ogerpon" => ShinyLock {
+        obtainable: false,
+        note: "Story encounter, shiny locked",
+    },
+    "terapagos" => ShinyLock {
+        obtainable: false,
+        note: "Story encounter, shiny locked",
+    },
+    "pecharunt" => ShinyLock {
+        obtainable: false,
+        note: "Mythical gift, shiny locked",
+    },
+    "ursaluna-bloodmoon" => ShinyLock {
+        obtainable: false,
+        note: "Story encounter, shiny locked",
+    },
+    "mew" => ShinyLock {
+        obtainable: false,
+        note: "Poke Ball Plus gift, shiny locked",
+    },
+    "mewtwo" => ShinyLock {
+        obtainable: false,
+        note: "7 star Tera Raid event, shiny locked",
+    },
+    "gimmighoul-roaming" => ShinyLock {
+        obtainable: true,
+        note: "Roaming form is shiny locked, hunt the chest form instead",
+    },
+    "walking-wake" => PARADOX_LEGEND,
+    "iron-leaves" => PARADOX_LEGEND,
+    "gouging-fire" => PARADOX_LEGEND,
+    "raging-bolt" => PARADOX_LEGEND,
+    "iron-boulder" => PARADOX_LEGEND,
+    "iron-crown" => PARADOX_LEGEND,
+    "articuno" => SNACKSWORTH,
+    "zapdos" => SNACKSWORTH,
+    "moltres" => SNACKSWORTH,
+    "raikou" => SNACKSWORTH,
+    "entei" => SNACKSWORTH,
+    "suicune" => SNACKSWORTH,
+    "lugia" => SNACKSWORTH,
+    "ho-oh" => SNACKSWORTH,
+    "latias" => SNACKSWORTH,
+    "latios" => SNACKSWORTH,
+    "kyogre" => SNACKSWORTH,
+    "groudon" => SNACKSWORTH,
+    "rayquaza" => SNACKSWORTH,
+    "cobalion" => SNACKSWORTH,
+    "terrakion" => SNACKSWORTH,
+    "virizion" => SNACKSWORTH,
+    "reshiram" => SNACKSWORTH,
+    "zekrom" => SNACKSWORTH,
+    "kyurem" => SNACKSWORTH,
+    "solgaleo" => SNACKSWORTH,
+    "lunala" => SNACKSWORTH,
+    "necrozma" => SNACKSWORTH,
+    "kubfu" => SNACKSWORTH,
+    "urshifu" => ShinyLock {
+        obtainable: false,
+        note: "Evolves from the shiny locked Snacksworth Kubfu",
+    },
+    "glastrier" => SNACKSWORTH,
+    "spectrier" => SNACKSWORTH,
+    "sprigatito" => GIFT_STARTER,
+    "fuecoco" => GIFT_STARTER,
+    "quaxly" => GIFT_STARTER,
+};
+
+pub fn shiny_lock(pokemon: &str) -> Option<&'static ShinyLock> {
+    let mut name = pokemon;
+    loop {
+        if let Some(lock) = SHINY_LOCKS.get(name) {
+            return Some(lock);
+        }
+        name = &name[..name.rfind('-')?];
+    }
+}
+
+/// Whether no shiny of this pokemon can be obtained at all.
+pub fn is_shiny_locked(pokemon: &str) -> bool {
+    shiny_lock(pokemon).is_some_and(|lock| !lock.obtainable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locked_forms_are_not_overridden_by_their_base_name() {
+        assert!(is_shiny_locked("ursaluna-bloodmoon"));
+        assert!(!is_shiny_locked("ursaluna"));
+        assert!(!is_shiny_locked("gimmighoul-roaming"));
+        assert!(shiny_lock("gimmighoul-roaming").is_some());
+        assert!(shiny_lock("gimmighoul").is_none());
+    }
+
+    #[test]
+    fn unlisted_forms_fall_back_to_their_base_name() {
+        assert!(is_shiny_locked("ogerpon-wellspring-mask"));
+        assert!(is_shiny_locked("urshifu-rapid-strike"));
+        assert!(is_shiny_locked("mewtwo"));
+        assert!(!is_shiny_locked("mr-mime"));
+    }
+}
@@ -0,0 +1,466 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing::warn;
+use dioxus_sdk::storage::{LocalStorage, SessionStorage, StorageBacking, StorageSubscriber};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+use crate::shiny_dex::caught::Caught;
+use crate::shiny_dex::exclusives::Version;
+use crate::shiny_dex::favourites::{Collections, DEFAULT_COLLECTION};
+use crate::shiny_dex::hunt::Hunts;
+use crate::shiny_dex::inventory::Inventory;
+use crate::shiny_dex::meal_timer::MealTimer;
+use crate::shiny_dex::outbreak::Outbreaks;
+use crate::shiny_dex::profiles::{Profiles, DEFAULT_PROFILE};
+use crate::shiny_dex::sync::SyncSettings;
+
+/// Where values are persisted. Unlike the session storage `use_persistent`
+/// used, it's shared between tabs, and tells each of the others' changes.
+type Backing = LocalStorage;
+
+/// A persisted value, tagged with the schema version it was written with.
+/// The value is kept as JSON so old versions can be migrated without their
+/// Rust types.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    data: String,
+}
+
+/// A value persisted under a fixed key.
+pub trait StorageKey: 'static {
+    type Value: Serialize + DeserializeOwned + Clone + PartialEq + 'static;
+
+    const KEY: &'static str;
+    /// Bump this when `Value` changes shape, and handle the old version in `migrate`.
+    const VERSION: u32 = 1;
+    /// Shared by all profiles rather than kept per profile.
+    const GLOBAL: bool = false;
+
+    fn init() -> Self::Value;
+
+    /// Upgrades data written by schema version `from` to version `from + 1`.
+    fn migrate(from: u32, _data: Value) -> Result<Value, String> {
+        Err(format!("no migration from version {from}"))
+    }
+
+    /// Data stored under `KEY` by `use_persistent` before values were
+    /// versioned, with the schema version it matches.
+    fn legacy() -> Option<(u32, Value)> {
+        let value = SessionStorage::get::<Self::Value>(&Self::KEY.to_string())?;
+        Some((1, serde_json::to_value(value).ok()?))
+    }
+}
+
+/// Where `K` is stored for `profile`. The default profile keeps the keys
+/// used before there were profiles.
+fn storage_key<K: StorageKey>(profile: &str) -> String {
+    if K::GLOBAL || profile == DEFAULT_PROFILE {
+        format!("store:{}", K::KEY)
+    } else {
+        format!("store:{profile}:{}", K::KEY)
+    }
+}
+
+/// Where the meal timer notes the last notification shown for `profile`,
+/// so only one tab shows it.
+pub fn meal_timer_notified_key(profile: &str) -> String {
+    format!("{}:notified", storage_key::<MealTimerKey>(profile))
+}
+
+/// Migrates data from `version` up to the key's current version and reads it.
+fn upgrade<K: StorageKey>(mut version: u32, mut data: Value) -> Result<K::Value, String> {
+    if version > K::VERSION {
+        return Err(format!(
+            "stored with version {version}, newer than the supported version {}",
+            K::VERSION
+        ));
+    }
+    while version < K::VERSION {
+        data = K::migrate(version, data)?;
+        version += 1;
+    }
+    serde_json::from_value(data).map_err(|err| err.to_string())
+}
+
+fn unpack(envelope: Envelope) -> Option<(u32, Value)> {
+    let data = serde_json::from_str(&envelope.data).ok()?;
+    Some((envelope.version, data))
+}
+
+fn load<K: StorageKey>(profile: &str) -> K::Value {
+    let key = storage_key::<K>(profile);
+    let stored = match Backing::get::<Envelope>(&key) {
+        Some(envelope) => unpack(envelope),
+        // Only the default profile has data from before profiles; versioned
+        // values were briefly kept in session storage too.
+        None if profile == DEFAULT_PROFILE => SessionStorage::get(&key)
+            .and_then(unpack)
+            .or_else(K::legacy),
+        None => None,
+    };
+    let Some((version, data)) = stored else {
+        return K::init();
+    };
+    upgrade::<K>(version, data.clone()).unwrap_or_else(|err| {
+        // Set the data aside rather than losing it when the default is saved over it.
+        warn!("Couldn't load {key}: {err}");
+        let envelope = Envelope {
+            version,
+            data: data.to_string(),
+        };
+        Backing::set(format!("{key}:unreadable"), &envelope);
+        K::init()
+    })
+}
+
+/// Packs a value for storage. It goes through `Value`, which sorts map
+/// keys, so equal values always encode the same even when they hold a
+/// `HashMap`.
+fn pack<K: StorageKey>(value: &K::Value) -> Result<Envelope, String> {
+    let data = serde_json::to_value(value).map_err(|err| err.to_string())?;
+    Ok(Envelope {
+        version: K::VERSION,
+        data: data.to_string(),
+    })
+}
+
+fn save<K: StorageKey>(profile: &str, value: &K::Value) {
+    match pack::<K>(value) {
+        Ok(envelope) => Backing::set(storage_key::<K>(profile), &envelope),
+        Err(err) => warn!("Couldn't save {}: {err}", K::KEY),
+    }
+}
+
+/// The signal shared by every `use_stored::<K>` call.
+struct Stored<K: StorageKey>(Signal<K::Value>);
+
+impl<K: StorageKey> Clone for Stored<K> {
+    fn clone(&self) -> Self {
+        Stored(self.0)
+    }
+}
+
+/// Loads the value for `K` in the active profile, migrating old data, and
+/// saves it whenever it changes. Switching profile swaps in that profile's
+/// value, and changes saved by other tabs are picked up as they happen.
+fn use_stored_provider<K: StorageKey>(profile: Memo<String>) -> Signal<K::Value> {
+    let mut value = use_hook(|| Signal::new(load::<K>(&profile.peek())));
+    // The profile `value` belongs to, which only changes along with `value`.
+    let mut loaded = use_signal(|| profile.peek().clone());
+    use_effect(move || {
+        let profile = profile();
+        if *loaded.peek() != profile {
+            value.set(load::<K>(&profile));
+            loaded.set(profile);
+        }
+    });
+    use_effect(move || save::<K>(&loaded.read(), &value.read()));
+    // A resource rather than a future so it restarts on the new key when switching profile.
+    let _listener = use_resource(move || async move {
+        let mut changes = Backing::subscribe::<Envelope>(&storage_key::<K>(&loaded()));
+        while changes.changed().await.is_ok() {
+            let envelope = changes.borrow().data::<Envelope>().cloned();
+            let Some((version, data)) = envelope.and_then(unpack) else {
+                continue;
+            };
+            match upgrade::<K>(version, data) {
+                // Saving it again writes the same bytes, as `pack` is
+                // deterministic, so no further event is fired and tabs don't echo.
+                Ok(updated) if *value.peek() != updated => value.set(updated),
+                Ok(_) => {}
+                Err(err) => warn!("Ignoring {} from another tab: {err}", K::KEY),
+            }
+        }
+    });
+    use_context_provider(|| Stored::<K>(value));
+    value
+}
+
+/// Provides every stored value to the rest of the app; call once from the layout.
+pub fn use_storage_provider() {
+    let global = use_memo(|| DEFAULT_PROFILE.to_string());
+    let profiles = use_stored_provider::<ProfilesKey>(global);
+    let profile = use_memo(move || profiles.read().active.clone());
+    use_stored_provider::<CollectionsKey>(profile);
+    use_stored_provider::<HuntsKey>(profile);
+    use_stored_provider::<CaughtKey>(profile);
+    use_stored_provider::<InventoryKey>(profile);
+    use_stored_provider::<OutbreaksKey>(profile);
+    use_stored_provider::<MealTimerKey>(profile);
+    use_stored_provider::<MyGameKey>(profile);
+    use_stored_provider::<SyncKey>(profile);
+}
+
+/// The stored value for `K`. Every call shares one signal, so a change made
+/// on one page shows up everywhere else.
+pub fn use_stored<K: StorageKey>() -> Signal<K::Value> {
+    use_context::<Stored<K>>().0
+}
+
+/// Favourites collections. Version 1 was the flat set of names stored as `faves`.
+pub struct CollectionsKey;
+
+impl StorageKey for CollectionsKey {
+    type Value = Collections;
+    const KEY: &'static str = "collections";
+    const VERSION: u32 = 2;
+
+    fn init() -> Collections {
+        Collections::new()
+    }
+
+    fn migrate(from: u32, data: Value) -> Result<Value, String> {
+        match from {
+            1 => {
+                let mut names =
+                    serde_json::from_value::<Vec<String>>(data).map_err(|err| err.to_string())?;
+                names.sort();
+                let entries = names
+                    .into_iter()
+                    .map(|pokemon| {
+                        json!({ "pokemon": pokemon, "note": "", "priority": "Normal", "method": null })
+                    })
+                    .collect::<Vec<_>>();
+                Ok(json!([{ "name": DEFAULT_COLLECTION, "entries": entries }]))
+            }
+            _ => Err(format!("no migration from version {from}")),
+        }
+    }
+
+    fn legacy() -> Option<(u32, Value)> {
+        if let Some(collections) = SessionStorage::get::<Collections>(&Self::KEY.to_string()) {
+            return Some((2, serde_json::to_value(collections).ok()?));
+        }
+        let faves = SessionStorage::get::<HashSet<String>>(&"faves".to_string())?;
+        Some((1, serde_json::to_value(faves).ok()?))
+    }
+}
+
+pub struct HuntsKey;
+
+impl StorageKey for HuntsKey {
+    type Value = Hunts;
+    const KEY: &'static str = "hunts";
+
+    fn init() -> Hunts {
+        Hunts::new()
+    }
+}
+
+pub struct CaughtKey;
+
+impl StorageKey for CaughtKey {
+    type Value = Caught;
+    const KEY: &'static str = "caught";
+
+    fn init() -> Caught {
+        Caught::new()
+    }
+}
+
+pub struct InventoryKey;
+
+impl StorageKey for InventoryKey {
+    type Value = Inventory;
+    const KEY: &'static str = "inventory";
+
+    fn init() -> Inventory {
+        Inventory::new()
+    }
+}
+
+pub struct OutbreaksKey;
+
+impl StorageKey for OutbreaksKey {
+    type Value = Outbreaks;
+    const KEY: &'static str = "outbreaks";
+
+    fn init() -> Outbreaks {
+        Outbreaks::new()
+    }
+}
+
+pub struct MealTimerKey;
+
+impl StorageKey for MealTimerKey {
+    type Value = Option<MealTimer>;
+    const KEY: &'static str = "meal_timer";
+
+    fn init() -> Option<MealTimer> {
+        None
+    }
+}
+
+pub struct MyGameKey;
+
+impl StorageKey for MyGameKey {
+    type Value = Option<Version>;
+    const KEY: &'static str = "my_game";
+
+    fn init() -> Option<Version> {
+        None
+    }
+}
+
+/// Sync settings. Version 1 didn't record when the data was last edited.
+pub struct SyncKey;
+
+impl StorageKey for SyncKey {
+    type Value = SyncSettings;
+    const KEY: &'static str = "sync";
+    const VERSION: u32 = 2;
+
+    fn init() -> SyncSettings {
+        SyncSettings::default()
+    }
+
+    fn migrate(from: u32, mut data: Value) -> Result<Value, String> {
+        match from {
+            1 => {
+                let settings = data.as_object_mut().ok_or("expected an object")?;
+                settings.insert("edited_at".to_string(), json!(0.0));
+                Ok(data)
+            }
+            _ => Err(format!("no migration from version {from}")),
+        }
+    }
+}
+
+pub struct ProfilesKey;
+
+impl StorageKey for ProfilesKey {
+    type Value = Profiles;
+    const KEY: &'static str = "profiles";
+    const GLOBAL: bool = true;
+
+    fn init() -> Profiles {
+        Profiles::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shiny_dex::favourites::{Collection, FavouriteEntry, Priority};
+    use crate::shiny_dex::hunt::{Hunt, HuntMethod};
+
+    #[test]
+    fn faves_are_moved_into_the_default_collection() {
+        let collections = upgrade::<CollectionsKey>(1, json!(["pikachu", "eevee"])).unwrap();
+
+        assert_eq!(
+            collections,
+            vec![Collection {
+                name: DEFAULT_COLLECTION.to_string(),
+                entries: vec![FavouriteEntry::new("eevee"), FavouriteEntry::new("pikachu")],
+            }]
+        );
+    }
+
+    #[test]
+    fn empty_faves_give_an_empty_default_collection() {
+        let collections = upgrade::<CollectionsKey>(1, json!([])).unwrap();
+
+        assert_eq!(collections, vec![Collection::new(DEFAULT_COLLECTION)]);
+    }
+
+    #[test]
+    fn current_collections_load_unchanged() {
+        let payload = json!([{
+            "name": "For trade",
+            "entries": [{ "pokemon": "gible", "note": "jolly", "priority": "High", "method": "Masuda" }],
+        }]);
+
+        let collections = upgrade::<CollectionsKey>(2, payload).unwrap();
+
+        assert_eq!(collections[0].name, "For trade");
+        assert_eq!(collections[0].entries[0].note, "jolly");
+        assert_eq!(collections[0].entries[0].priority, Priority::High);
+        assert_eq!(collections[0].entries[0].method, Some(HuntMethod::Masuda));
+    }
+
+    #[test]
+    fn malformed_faves_fail_to_migrate() {
+        assert!(upgrade::<CollectionsKey>(1, json!({ "pikachu": true })).is_err());
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        assert!(upgrade::<CollectionsKey>(3, json!([])).is_err());
+        assert!(upgrade::<HuntsKey>(2, json!({})).is_err());
+    }
+
+    #[test]
+    fn profiles_namespace_keys() {
+        assert_eq!(storage_key::<HuntsKey>(DEFAULT_PROFILE), "store:hunts");
+        assert_eq!(storage_key::<HuntsKey>("Kieran"), "store:Kieran:hunts");
+        assert_eq!(storage_key::<ProfilesKey>("Kieran"), "store:profiles");
+        assert_eq!(
+            meal_timer_notified_key("Kieran"),
+            "store:Kieran:meal_timer:notified"
+        );
+    }
+
+    #[test]
+    fn version_one_sync_settings_were_never_edited() {
+        let payload = json!({ "backend": "Rest", "url": "https://example.com", "strategy": "LastWriterWins", "base": null });
+
+        let settings = upgrade::<SyncKey>(1, payload).unwrap();
+
+        assert_eq!(settings.url, "https://example.com");
+        assert_eq!(settings.edited_at, 0.0);
+    }
+
+    #[test]
+    fn packing_is_independent_of_map_order() {
+        let names = (0..50).map(|i| format!("pokemon-{i}")).collect::<Vec<_>>();
+        let forwards = names
+            .iter()
+            .map(|name| (name.clone(), Hunt::default()))
+            .collect::<Hunts>();
+        let backwards = names
+            .iter()
+            .rev()
+            .map(|name| (name.clone(), Hunt::default()))
+            .collect::<Hunts>();
+
+        assert_eq!(
+            pack::<HuntsKey>(&forwards).unwrap(),
+            pack::<HuntsKey>(&backwards).unwrap()
+        );
+    }
+
+    #[test]
+    fn version_one_hunts_load() {
+        let payload = json!({
+            "ralts": {
+                "encounters": 120,
+                "method": "Outbreak",
+                "sandwich_level": 3,
+                "shiny_charm": true,
+                "started_at": 1700000000000.0,
+                "completed_at": null,
+                "phases": [],
+                "sessions": [],
+                "session_open": false,
+            }
+        });
+
+        let hunts = upgrade::<HuntsKey>(1, payload).unwrap();
+
+        assert_eq!(
+            hunts.get("ralts"),
+            Some(&Hunt {
+                encounters: 120,
+                method: HuntMethod::Outbreak,
+                sandwich_level: 3,
+                shiny_charm: true,
+                started_at: 1700000000000.0,
+                ..Default::default()
+            })
+        );
+    }
+}
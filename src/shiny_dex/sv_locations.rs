@@ -0,0 +1,582 @@
+use phf::phf_map;
+
+pub const WALK: &str = "walk";
+pub const SURF: &str = "surf";
+pub const GIFT: &str = "gift";
+/// A one-off encounter, like a legendary waiting somewhere on the map.
+pub const STATIC: &str = "static";
+
+/// The levels wild pokemon appear at in each area, before the post game.
+pub static SV_AREAS: phf::Map<&'static str, (i64, i64)> = phf_map! {
+    "cabo-poco" => (2, 5),
+    "poco-path" => (2, 5),
+    "south-province-area-one" => (2, 8),
+    "south-province-area-two" => (6, 12),
+    "south-province-area-three" => (16, 22),
+    "south-province-area-four" => (8, 14),
+    "south-province-area-five" => (18, 24),
+    "south-province-area-six" => (22, 28),
+    "east-province-area-one" => (14, 20),
+    "east-province-area-two" => (18, 26),
+    "east-province-area-three" => (26, 32),
+    "west-province-area-one" => (10, 16),
+    "west-province-area-two" => (24, 30),
+    "west-province-area-three" => (28, 34),
+    "asado-desert" => (20, 30),
+    "tagtree-thicket" => (32, 40),
+    "north-province-area-one" => (40, 48),
+    "north-province-area-two" => (38, 46),
+    "north-province-area-three" => (46, 54),
+    "glaseado-mountain" => (34, 44),
+    "dalizapa-passage" => (40, 46),
+    "casseroya-lake" => (50, 58),
+    "socarrat-trail" => (45, 55),
+    "alfornada-cavern" => (30, 45),
+    "area-zero" => (55, 66),
+    "south-paldean-sea" => (10, 30),
+    "west-paldean-sea" => (30, 45),
+    "east-paldean-sea" => (25, 40),
+    "north-paldean-sea" => (45, 55),
+    "kitakami-road" => (50, 58),
+    "apple-hills" => (54, 62),
+    "revelers-road" => (52, 60),
+    "oni-mountain" => (58, 66),
+    "timeless-woods" => (60, 68),
+    "paradise-barrens" => (56, 64),
+    "crystal-pool" => (58, 66),
+    "infernal-pass" => (60, 70),
+    "chilling-waterhead" => (58, 66),
+    "wistful-fields" => (52, 60),
+    "mossui-town" => (50, 56),
+    "fellhorn-gorge" => (60, 68),
+    "kitakami-wilds" => (54, 64),
+    "savanna-biome" => (62, 70),
+    "coastal-biome" => (62, 70),
+    "canyon-biome" => (63, 72),
+    "polar-biome" => (64, 72),
+    "area-zero-underdepths" => (70, 75),
+};
+
+/// Where each pokemon can be found in Paldea, Kitakami and the Blueberry
+/// Academy Terarium, by area and method. Evolutions only met by evolving
+/// aren't listed.
+pub static SV_LOCATIONS: phf::Map<&'static str, &'static [(&'static str, &'static str)]> = phf_map! {
+    // Paldea
+    "sprigatito" => &[("cabo-poco", GIFT)],
+    "fuecoco" => &[("cabo-poco", GIFT)],
+    "quaxly" => &[("cabo-poco", GIFT)],
+    "lechonk" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "oinkologne" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "tarountula" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("west-province-area-one", WALK)],
+    "spidops" => &[("south-province-area-three", WALK), ("west-province-area-one", WALK), ("tagtree-thicket", WALK)],
+    "nymble" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "lokix" => &[("east-province-area-three", WALK), ("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "hoppip" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "skiploom" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("north-province-area-one", WALK)],
+    "jumpluff" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK)],
+    "fletchling" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "fletchinder" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "talonflame" => &[("north-province-area-one", WALK), ("north-province-area-three", WALK), ("casseroya-lake", WALK)],
+    "pawmi" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-three", WALK)],
+    "pawmo" => &[("south-province-area-three", WALK), ("east-province-area-one", WALK)],
+    "houndour" => &[("south-province-area-six", WALK), ("east-province-area-two", WALK), ("asado-desert", WALK)],
+    "houndoom" => &[("asado-desert", WALK), ("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "yungoos" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK), ("west-province-area-one", WALK)],
+    "gumshoos" => &[("south-province-area-five", WALK), ("west-province-area-two", WALK)],
+    "skwovet" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "greedent" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "sunkern" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "sunflora" => &[("south-province-area-four", WALK), ("west-province-area-one", WALK)],
+    "kricketot" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "kricketune" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "scatterbug" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK)],
+    "spewpa" => &[("south-province-area-three", WALK), ("south-province-area-four", WALK)],
+    "vivillon" => &[("south-province-area-five", WALK), ("north-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "combee" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "vespiquen" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "rookidee" => &[("south-province-area-two", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "corvisquire" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "corviknight" => &[("north-province-area-three", WALK), ("glaseado-mountain", WALK)],
+    "happiny" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "chansey" => &[("south-province-area-five", WALK), ("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "blissey" => &[("north-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "azurill" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK)],
+    "marill" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK)],
+    "azumarill" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "surskit" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "masquerain" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "buizel" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-three", WALK), ("east-province-area-one", WALK)],
+    "floatzel" => &[("east-province-area-two", WALK), ("north-paldean-sea", SURF)],
+    "wooper" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK), ("south-province-area-four", WALK), ("asado-desert", WALK)],
+    "clodsire" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "psyduck" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK)],
+    "golduck" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "chewtle" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "drednaw" => &[("south-province-area-five", WALK), ("east-province-area-two", WALK), ("casseroya-lake", WALK)],
+    "igglybuff" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK)],
+    "jigglypuff" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "wigglytuff" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "ralts" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "kirlia" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "gardevoir" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK)],
+    "gallade" => &[("north-province-area-one", WALK)],
+    "drowzee" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "hypno" => &[("south-province-area-six", WALK), ("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "gastly" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "haunter" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("tagtree-thicket", WALK)],
+    "gengar" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK), ("north-province-area-three", WALK)],
+    "tandemaus" => &[("south-province-area-three", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK)],
+    "maushold" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "pichu" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK)],
+    "pikachu" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "raichu" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "fidough" => &[("south-province-area-three", WALK), ("south-province-area-four", WALK), ("east-province-area-one", WALK)],
+    "dachsbun" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "slakoth" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "vigoroth" => &[("west-province-area-two", WALK), ("tagtree-thicket", WALK)],
+    "slaking" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "bounsweet" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "steenee" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "tsareena" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "smoliv" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK), ("west-province-area-one", WALK)],
+    "dolliv" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "arboliva" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "bonsly" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "sudowoodo" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "rockruff" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "lycanroc" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "rolycoly" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("asado-desert", WALK)],
+    "carkol" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "coalossal" => &[("north-province-area-three", WALK), ("socarrat-trail", WALK)],
+    "shinx" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "luxio" => &[("south-province-area-six", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "luxray" => &[("north-province-area-one", WALK), ("north-province-area-three", WALK)],
+    "starly" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("west-province-area-one", WALK)],
+    "staravia" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "staraptor" => &[("north-province-area-one", WALK), ("north-province-area-three", WALK)],
+    "oricorio" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "mareep" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "flaaffy" => &[("east-province-area-one", WALK), ("west-province-area-two", WALK)],
+    "ampharos" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK)],
+    "petilil" => &[("south-province-area-two", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "lilligant" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "shroomish" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("tagtree-thicket", WALK)],
+    "breloom" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "applin" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK)],
+    "trapinch" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "vibrava" => &[("asado-desert", WALK)],
+    "flygon" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "axew" => &[("east-province-area-three", WALK), ("tagtree-thicket", WALK), ("north-province-area-two", WALK)],
+    "fraxure" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "haxorus" => &[("north-province-area-three", WALK), ("casseroya-lake", WALK)],
+    "cetoddle" => &[("north-province-area-one", WALK), ("glaseado-mountain", WALK)],
+    "cetitan" => &[("glaseado-mountain", WALK)],
+    "phanpy" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "donphan" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "cufant" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "copperajah" => &[("tagtree-thicket", WALK), ("north-province-area-three", WALK)],
+    "gible" => &[("glaseado-mountain", WALK), ("dalizapa-passage", WALK), ("alfornada-cavern", WALK)],
+    "gabite" => &[("glaseado-mountain", WALK), ("dalizapa-passage", WALK)],
+    "garchomp" => &[("north-province-area-two", WALK), ("area-zero", WALK)],
+    "nacli" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "naclstack" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "garganacl" => &[("north-province-area-one", WALK), ("socarrat-trail", WALK)],
+    "wingull" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-paldean-sea", SURF)],
+    "pelipper" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("south-paldean-sea", SURF)],
+    "tadbulb" => &[("south-province-area-three", WALK), ("east-province-area-one", WALK), ("east-province-area-two", WALK)],
+    "bellibolt" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "goomy" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "sliggoo" => &[("west-province-area-two", WALK), ("casseroya-lake", WALK)],
+    "goodra" => &[("north-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "croagunk" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("asado-desert", WALK)],
+    "toxicroak" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "wattrel" => &[("south-province-area-six", WALK), ("east-province-area-one", WALK), ("east-province-area-two", WALK), ("east-paldean-sea", SURF)],
+    "kilowattrel" => &[("north-province-area-one", WALK), ("casseroya-lake", WALK), ("north-paldean-sea", SURF)],
+    "eevee" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-province-area-four", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "dunsparce" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "deerling" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "sawsbuck" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK), ("north-province-area-three", WALK)],
+    "girafarig" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "grimer" => &[("south-province-area-five", WALK), ("east-province-area-two", WALK), ("alfornada-cavern", WALK)],
+    "muk" => &[("north-province-area-one", WALK), ("alfornada-cavern", WALK)],
+    "maschiff" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK), ("south-province-area-four", WALK)],
+    "mabosstiff" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "toxel" => &[("south-province-area-three", WALK), ("south-province-area-four", WALK)],
+    "toxtricity" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "dedenne" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "pachirisu" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "shroodle" => &[("south-province-area-three", WALK), ("south-province-area-four", WALK), ("asado-desert", WALK)],
+    "grafaiai" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "stantler" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "foongus" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("tagtree-thicket", WALK)],
+    "amoonguss" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "voltorb" => &[("south-province-area-six", WALK), ("east-province-area-one", WALK), ("east-province-area-two", WALK)],
+    "electrode" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "magnemite" => &[("south-province-area-six", WALK), ("east-province-area-one", WALK), ("east-province-area-two", WALK)],
+    "magneton" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK)],
+    "magnezone" => &[("north-province-area-three", WALK)],
+    "ditto" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "growlithe" => &[("west-province-area-one", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "arcanine" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "teddiursa" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "ursaring" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "zangoose" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "seviper" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "swablu" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "altaria" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "skiddo" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "gogoat" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "tauros" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK), ("west-province-area-two", WALK)],
+    "litleo" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("asado-desert", WALK)],
+    "pyroar" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "stunky" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "skuntank" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "sneasel" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "weavile" => &[("north-province-area-three", WALK), ("glaseado-mountain", WALK)],
+    "murkrow" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "honchkrow" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "gothita" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "gothorita" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "gothitelle" => &[("north-province-area-one", WALK)],
+    "sinistea" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "polteageist" => &[("north-province-area-one", WALK)],
+    "mimikyu" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "klefki" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "indeedee" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "bramblin" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "brambleghast" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "toedscool" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("tagtree-thicket", WALK)],
+    "toedscruel" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "tropius" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "fomantis" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "lurantis" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "klawf" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "capsakid" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "scovillain" => &[("asado-desert", WALK), ("tagtree-thicket", WALK)],
+    "cacnea" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "cacturne" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "rellor" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "rabsca" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "venonat" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK)],
+    "venomoth" => &[("west-province-area-two", WALK), ("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "pineco" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("tagtree-thicket", WALK)],
+    "forretress" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "scyther" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "scizor" => &[("north-province-area-one", WALK)],
+    "heracross" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("tagtree-thicket", WALK)],
+    "hippopotas" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "hippowdon" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "salandit" => &[("south-province-area-three", WALK), ("south-province-area-six", WALK), ("asado-desert", WALK)],
+    "salazzle" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "larvitar" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "pupitar" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "tyranitar" => &[("north-province-area-three", WALK), ("casseroya-lake", WALK)],
+    "bagon" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "shelgon" => &[("north-province-area-one", WALK), ("north-province-area-two", WALK)],
+    "salamence" => &[("north-province-area-three", WALK)],
+    "drifloon" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "drifblim" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "misdreavus" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "mismagius" => &[("north-province-area-one", WALK)],
+    "gulpin" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "swalot" => &[("east-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "hawlucha" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "cyclizar" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "orthworm" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "silicobra" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "sandaconda" => &[("asado-desert", WALK)],
+    "tinkatink" => &[("south-province-area-two", WALK), ("south-province-area-three", WALK), ("south-province-area-four", WALK), ("south-province-area-five", WALK)],
+    "tinkatuff" => &[("east-province-area-one", WALK), ("west-province-area-one", WALK), ("west-province-area-two", WALK)],
+    "tinkaton" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "wiglett" => &[("poco-path", WALK), ("south-province-area-one", WALK), ("south-province-area-two", WALK), ("south-paldean-sea", WALK)],
+    "wugtrio" => &[("south-province-area-five", WALK), ("east-province-area-two", WALK), ("west-province-area-three", WALK)],
+    "bombirdier" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "finizen" => &[("south-paldean-sea", SURF), ("west-paldean-sea", SURF), ("east-paldean-sea", SURF), ("north-paldean-sea", SURF)],
+    "tatsugiri" => &[("north-province-area-three", WALK), ("casseroya-lake", SURF)],
+    "cramorant" => &[("south-province-area-three", WALK), ("east-province-area-one", WALK), ("south-paldean-sea", SURF), ("casseroya-lake", SURF)],
+    "flittle" => &[("east-province-area-two", WALK), ("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "espathra" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "varoom" => &[("east-province-area-three", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "revavroom" => &[("north-province-area-one", WALK), ("socarrat-trail", WALK)],
+    "glimmet" => &[("glaseado-mountain", WALK), ("north-province-area-one", WALK), ("alfornada-cavern", WALK)],
+    "glimmora" => &[("alfornada-cavern", WALK)],
+    "greavard" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "houndstone" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "flamigo" => &[("south-province-area-six", WALK), ("east-province-area-one", WALK), ("casseroya-lake", WALK)],
+    "veluza" => &[("west-paldean-sea", SURF), ("north-paldean-sea", SURF), ("casseroya-lake", SURF)],
+    "dondozo" => &[("west-paldean-sea", SURF), ("north-paldean-sea", SURF), ("casseroya-lake", SURF)],
+    "frigibax" => &[("glaseado-mountain", WALK)],
+    "arctibax" => &[("glaseado-mountain", WALK)],
+    "baxcalibur" => &[("glaseado-mountain", WALK)],
+    "gimmighoul" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "sandile" => &[("asado-desert", WALK)],
+    "krokorok" => &[("asado-desert", WALK)],
+    "krookodile" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "magikarp" => &[("south-province-area-one", SURF), ("south-province-area-two", SURF), ("south-province-area-four", SURF), ("east-province-area-one", SURF)],
+    "gyarados" => &[("south-province-area-five", SURF), ("east-province-area-two", SURF), ("casseroya-lake", SURF)],
+    "arrokuda" => &[("south-paldean-sea", SURF), ("west-paldean-sea", SURF), ("east-paldean-sea", SURF)],
+    "barraskewda" => &[("west-paldean-sea", SURF), ("north-paldean-sea", SURF)],
+    "mareanie" => &[("south-paldean-sea", WALK), ("east-paldean-sea", WALK), ("west-paldean-sea", WALK)],
+    "toxapex" => &[("west-paldean-sea", WALK)],
+    "shellder" => &[("east-paldean-sea", SURF), ("west-paldean-sea", SURF)],
+    "cloyster" => &[("north-paldean-sea", SURF)],
+    "luvdisc" => &[("south-paldean-sea", SURF), ("east-paldean-sea", SURF), ("west-paldean-sea", SURF)],
+    "alomomola" => &[("west-paldean-sea", SURF), ("north-paldean-sea", SURF)],
+    "tentacool" => &[("south-paldean-sea", SURF), ("east-paldean-sea", SURF), ("west-paldean-sea", SURF)],
+    "tentacruel" => &[("west-paldean-sea", SURF), ("north-paldean-sea", SURF)],
+    "shellos" => &[("south-province-area-one", WALK), ("south-province-area-two", WALK), ("east-province-area-one", WALK), ("south-paldean-sea", WALK)],
+    "gastrodon" => &[("east-province-area-two", WALK), ("west-province-area-three", WALK), ("casseroya-lake", WALK)],
+    "basculin" => &[("south-province-area-two", SURF), ("south-province-area-three", SURF), ("east-province-area-one", SURF)],
+    "lapras" => &[("casseroya-lake", SURF), ("north-paldean-sea", SURF)],
+    "deino" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "zweilous" => &[("north-province-area-one", WALK)],
+    "hydreigon" => &[("north-province-area-three", WALK)],
+    "jangmo-o" => &[("north-province-area-one", WALK), ("dalizapa-passage", WALK)],
+    "hakamo-o" => &[("north-province-area-one", WALK)],
+    "kommo-o" => &[("north-province-area-three", WALK)],
+    "dreepy" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "drakloak" => &[("north-province-area-one", WALK)],
+    "dragapult" => &[("north-province-area-three", WALK)],
+    "noibat" => &[("dalizapa-passage", WALK), ("alfornada-cavern", WALK)],
+    "noivern" => &[("north-province-area-one", WALK), ("glaseado-mountain", WALK)],
+    "meditite" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "medicham" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "riolu" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "lucario" => &[("north-province-area-one", WALK)],
+    "falinks" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("north-province-area-one", WALK)],
+    "pawniard" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "bisharp" => &[("tagtree-thicket", WALK), ("north-province-area-one", WALK)],
+    "mankey" => &[("south-province-area-five", WALK), ("west-province-area-two", WALK)],
+    "primeape" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "crabrawler" => &[("north-province-area-one", WALK), ("glaseado-mountain", WALK)],
+    "crabominable" => &[("glaseado-mountain", WALK)],
+    "makuhita" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "hariyama" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "hatenna" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "hattrem" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "hatterene" => &[("north-province-area-one", WALK)],
+    "impidimp" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "morgrem" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "grimmsnarl" => &[("north-province-area-one", WALK)],
+    "sableye" => &[("alfornada-cavern", WALK)],
+    "rotom" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "mudbray" => &[("south-province-area-three", WALK), ("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "mudsdale" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "torkoal" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "squawkabilly" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "snom" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "frosmoth" => &[("glaseado-mountain", WALK)],
+    "snorunt" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "glalie" => &[("glaseado-mountain", WALK)],
+    "froslass" => &[("glaseado-mountain", WALK)],
+    "snover" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "abomasnow" => &[("glaseado-mountain", WALK)],
+    "bergmite" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "avalugg" => &[("glaseado-mountain", WALK)],
+    "delibird" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "swinub" => &[("north-province-area-one", WALK), ("glaseado-mountain", WALK)],
+    "piloswine" => &[("glaseado-mountain", WALK)],
+    "cubchoo" => &[("north-province-area-two", WALK), ("glaseado-mountain", WALK)],
+    "beartic" => &[("glaseado-mountain", WALK)],
+    "eiscue" => &[("glaseado-mountain", WALK)],
+    "charcadet" => &[("west-province-area-two", WALK), ("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "flabebe" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "floette" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "florges" => &[("north-province-area-one", WALK)],
+    "skrelp" => &[("south-paldean-sea", SURF), ("west-paldean-sea", SURF)],
+    "dragalge" => &[("west-paldean-sea", SURF)],
+    "clauncher" => &[("south-paldean-sea", SURF), ("west-paldean-sea", SURF)],
+    "clawitzer" => &[("west-paldean-sea", SURF)],
+    "oranguru" => &[("tagtree-thicket", WALK)],
+    "passimian" => &[("tagtree-thicket", WALK)],
+    "stonjourner" => &[("north-province-area-one", WALK)],
+    "gligar" => &[("west-province-area-two", WALK), ("asado-desert", WALK)],
+    "gliscor" => &[("north-province-area-one", WALK)],
+    "volbeat" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "illumise" => &[("south-province-area-four", WALK), ("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "shuckle" => &[("east-province-area-two", WALK), ("asado-desert", WALK)],
+    "larvesta" => &[("asado-desert", WALK)],
+    "volcarona" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "espurr" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK)],
+    "meowstic" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "pincurchin" => &[("south-paldean-sea", WALK), ("east-paldean-sea", WALK)],
+    "sandygast" => &[("south-paldean-sea", WALK), ("east-paldean-sea", WALK)],
+    "palossand" => &[("north-province-area-one", WALK)],
+    "diglett" => &[("south-province-area-five", WALK), ("west-province-area-one", WALK), ("asado-desert", WALK)],
+    "dugtrio" => &[("asado-desert", WALK), ("north-province-area-one", WALK)],
+    "meowth" => &[("south-province-area-five", WALK), ("east-province-area-one", WALK), ("west-province-area-one", WALK)],
+    "persian" => &[("west-province-area-two", WALK), ("north-province-area-one", WALK)],
+    "great-tusk" => &[("area-zero", WALK)],
+    "scream-tail" => &[("area-zero", WALK)],
+    "brute-bonnet" => &[("area-zero", WALK)],
+    "flutter-mane" => &[("area-zero", WALK)],
+    "slither-wing" => &[("area-zero", WALK)],
+    "sandy-shocks" => &[("area-zero", WALK)],
+    "roaring-moon" => &[("area-zero", WALK)],
+    "iron-treads" => &[("area-zero", WALK)],
+    "iron-bundle" => &[("area-zero", WALK)],
+    "iron-hands" => &[("area-zero", WALK)],
+    "iron-jugulis" => &[("area-zero", WALK)],
+    "iron-moth" => &[("area-zero", WALK)],
+    "iron-thorns" => &[("area-zero", WALK)],
+    "iron-valiant" => &[("area-zero", WALK)],
+    "ting-lu" => &[("socarrat-trail", STATIC)],
+    "chien-pao" => &[("glaseado-mountain", STATIC)],
+    "wo-chien" => &[("tagtree-thicket", STATIC)],
+    "chi-yu" => &[("west-province-area-two", STATIC)],
+    // Kitakami
+    "ekans" => &[("kitakami-road", WALK), ("apple-hills", WALK), ("wistful-fields", WALK)],
+    "arbok" => &[("oni-mountain", WALK), ("infernal-pass", WALK)],
+    "poliwag" => &[("kitakami-road", WALK), ("mossui-town", WALK), ("crystal-pool", WALK)],
+    "poliwhirl" => &[("chilling-waterhead", WALK), ("crystal-pool", WALK)],
+    "poliwrath" => &[("chilling-waterhead", WALK)],
+    "politoed" => &[("chilling-waterhead", WALK)],
+    "bellsprout" => &[("apple-hills", WALK), ("wistful-fields", WALK)],
+    "weepinbell" => &[("oni-mountain", WALK)],
+    "victreebel" => &[("oni-mountain", WALK), ("timeless-woods", WALK)],
+    "spinarak" => &[("kitakami-road", WALK), ("apple-hills", WALK), ("timeless-woods", WALK)],
+    "ariados" => &[("timeless-woods", WALK), ("oni-mountain", WALK)],
+    "yanma" => &[("kitakami-road", WALK), ("crystal-pool", WALK), ("wistful-fields", WALK)],
+    "yanmega" => &[("crystal-pool", WALK)],
+    "hoothoot" => &[("kitakami-road", WALK), ("apple-hills", WALK), ("timeless-woods", WALK)],
+    "noctowl" => &[("timeless-woods", WALK), ("oni-mountain", WALK)],
+    "aipom" => &[("kitakami-road", WALK), ("timeless-woods", WALK)],
+    "ambipom" => &[("timeless-woods", WALK)],
+    "sentret" => &[("kitakami-road", WALK), ("apple-hills", WALK), ("wistful-fields", WALK)],
+    "furret" => &[("apple-hills", WALK), ("oni-mountain", WALK)],
+    "seedot" => &[("kitakami-road", WALK), ("apple-hills", WALK)],
+    "nuzleaf" => &[("timeless-woods", WALK), ("oni-mountain", WALK)],
+    "shiftry" => &[("oni-mountain", WALK)],
+    "lotad" => &[("kitakami-road", WALK), ("crystal-pool", WALK), ("mossui-town", WALK)],
+    "lombre" => &[("crystal-pool", WALK), ("chilling-waterhead", WALK)],
+    "ludicolo" => &[("chilling-waterhead", WALK)],
+    "timburr" => &[("oni-mountain", WALK), ("infernal-pass", WALK)],
+    "gurdurr" => &[("infernal-pass", WALK)],
+    "conkeldurr" => &[("infernal-pass", WALK)],
+    "koffing" => &[("infernal-pass", WALK), ("fellhorn-gorge", WALK)],
+    "weezing" => &[("infernal-pass", WALK)],
+    "mienfoo" => &[("oni-mountain", WALK), ("fellhorn-gorge", WALK)],
+    "mienshao" => &[("fellhorn-gorge", WALK)],
+    "duskull" => &[("timeless-woods", WALK), ("kitakami-wilds", WALK)],
+    "dusclops" => &[("timeless-woods", WALK)],
+    "dusknoir" => &[("timeless-woods", WALK)],
+    "chingling" => &[("oni-mountain", WALK), ("kitakami-wilds", WALK)],
+    "chimecho" => &[("oni-mountain", WALK)],
+    "cleffa" => &[("apple-hills", WALK), ("oni-mountain", WALK)],
+    "clefairy" => &[("oni-mountain", WALK)],
+    "clefable" => &[("oni-mountain", WALK)],
+    "munchlax" => &[("apple-hills", WALK), ("kitakami-road", WALK)],
+    "snorlax" => &[("apple-hills", WALK)],
+    "barboach" => &[("kitakami-road", SURF), ("crystal-pool", SURF), ("mossui-town", SURF)],
+    "whiscash" => &[("crystal-pool", SURF)],
+    "feebas" => &[("crystal-pool", SURF)],
+    "milotic" => &[("crystal-pool", SURF)],
+    "pansage" => &[("apple-hills", WALK), ("kitakami-wilds", WALK)],
+    "pansear" => &[("infernal-pass", WALK), ("kitakami-wilds", WALK)],
+    "panpour" => &[("crystal-pool", WALK), ("kitakami-wilds", WALK)],
+    "vullaby" => &[("oni-mountain", WALK), ("fellhorn-gorge", WALK)],
+    "mandibuzz" => &[("fellhorn-gorge", WALK)],
+    "litwick" => &[("timeless-woods", WALK), ("oni-mountain", WALK)],
+    "lampent" => &[("timeless-woods", WALK)],
+    "chandelure" => &[("oni-mountain", WALK)],
+    "phantump" => &[("timeless-woods", WALK)],
+    "trevenant" => &[("timeless-woods", WALK)],
+    "poltchageist" => &[("timeless-woods", WALK), ("oni-mountain", WALK)],
+    "dipplin" => &[("apple-hills", WALK)],
+    "ogerpon" => &[("kitakami-wilds", STATIC)],
+    "okidogi" => &[("revelers-road", STATIC)],
+    "munkidori" => &[("paradise-barrens", STATIC)],
+    "fezandipiti" => &[("apple-hills", STATIC)],
+    "ursaluna-bloodmoon" => &[("timeless-woods", STATIC)],
+    // Blueberry
+    "doduo" => &[("savanna-biome", WALK)],
+    "dodrio" => &[("savanna-biome", WALK)],
+    "exeggcute" => &[("coastal-biome", WALK)],
+    "exeggutor" => &[("coastal-biome", WALK)],
+    "rhyhorn" => &[("canyon-biome", WALK), ("savanna-biome", WALK)],
+    "rhydon" => &[("canyon-biome", WALK)],
+    "horsea" => &[("coastal-biome", SURF)],
+    "seadra" => &[("coastal-biome", SURF)],
+    "kingdra" => &[("coastal-biome", SURF)],
+    "elekid" => &[("savanna-biome", WALK)],
+    "electabuzz" => &[("savanna-biome", WALK)],
+    "magby" => &[("canyon-biome", WALK)],
+    "magmar" => &[("canyon-biome", WALK)],
+    "chinchou" => &[("coastal-biome", SURF)],
+    "lanturn" => &[("coastal-biome", SURF)],
+    "skarmory" => &[("canyon-biome", WALK)],
+    "slugma" => &[("canyon-biome", WALK)],
+    "magcargo" => &[("canyon-biome", WALK)],
+    "plusle" => &[("savanna-biome", WALK)],
+    "minun" => &[("savanna-biome", WALK)],
+    "wailmer" => &[("coastal-biome", SURF)],
+    "wailord" => &[("coastal-biome", SURF)],
+    "beldum" => &[("canyon-biome", WALK)],
+    "metang" => &[("canyon-biome", WALK)],
+    "metagross" => &[("canyon-biome", WALK)],
+    "tynamo" => &[("coastal-biome", SURF)],
+    "eelektrik" => &[("coastal-biome", SURF)],
+    "blitzle" => &[("savanna-biome", WALK)],
+    "zebstrika" => &[("savanna-biome", WALK)],
+    "ducklett" => &[("coastal-biome", WALK)],
+    "swanna" => &[("coastal-biome", WALK)],
+    "minccino" => &[("savanna-biome", WALK)],
+    "cinccino" => &[("savanna-biome", WALK)],
+    "golett" => &[("canyon-biome", WALK)],
+    "golurk" => &[("canyon-biome", WALK)],
+    "rufflet" => &[("canyon-biome", WALK)],
+    "braviary" => &[("canyon-biome", WALK)],
+    "grubbin" => &[("savanna-biome", WALK)],
+    "charjabug" => &[("savanna-biome", WALK)],
+    "vikavolt" => &[("savanna-biome", WALK)],
+    "minior" => &[("canyon-biome", WALK)],
+    "cryogonal" => &[("polar-biome", WALK)],
+    "dratini" => &[("coastal-biome", WALK)],
+    "dragonair" => &[("coastal-biome", WALK)],
+    "duraludon" => &[("canyon-biome", WALK)],
+    "bulbasaur" => &[("savanna-biome", WALK)],
+    "charmander" => &[("canyon-biome", WALK)],
+    "squirtle" => &[("coastal-biome", WALK)],
+    "chikorita" => &[("savanna-biome", WALK)],
+    "cyndaquil" => &[("canyon-biome", WALK)],
+    "totodile" => &[("coastal-biome", WALK)],
+    "treecko" => &[("savanna-biome", WALK)],
+    "torchic" => &[("canyon-biome", WALK)],
+    "mudkip" => &[("coastal-biome", WALK)],
+    "turtwig" => &[("savanna-biome", WALK)],
+    "chimchar" => &[("canyon-biome", WALK)],
+    "piplup" => &[("polar-biome", WALK)],
+    "snivy" => &[("savanna-biome", WALK)],
+    "tepig" => &[("canyon-biome", WALK)],
+    "oshawott" => &[("coastal-biome", WALK)],
+    "chespin" => &[("savanna-biome", WALK)],
+    "fennekin" => &[("canyon-biome", WALK)],
+    "froakie" => &[("coastal-biome", WALK)],
+    "rowlet" => &[("savanna-biome", WALK)],
+    "litten" => &[("canyon-biome", WALK)],
+    "popplio" => &[("polar-biome", WALK)],
+    "terapagos" => &[("area-zero-underdepths", STATIC)],
+    "gouging-fire" => &[("area-zero", STATIC)],
+    "raging-bolt" => &[("area-zero", STATIC)],
+    "iron-boulder" => &[("area-zero", STATIC)],
+    "iron-crown" => &[("area-zero", STATIC)],
+    "vulpix-alola" => &[("polar-biome", WALK)],
+    "sandshrew-alola" => &[("polar-biome", WALK)],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_area_has_levels() {
+        for (pokemon, entries) in SV_LOCATIONS.entries() {
+            for (area, _) in entries.iter() {
+                assert!(
+                    SV_AREAS.contains_key(area),
+                    "{pokemon} is in unknown area {area}"
+                );
+            }
+        }
+    }
+}
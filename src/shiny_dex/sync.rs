@@ -0,0 +1,836 @@
+use dioxus::prelude::*;
+use dioxus_sdk::utils::timing::use_interval;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use crate::footer;
+use crate::shiny_dex::backup::{download, parse_backup, Backup, BACKUP_VERSION};
+use crate::shiny_dex::favourites::{Collection, Collections};
+use crate::shiny_dex::hunt::{Hunt, Hunts};
+use crate::shiny_dex::storage::{use_stored, CollectionsKey, HuntsKey, SyncKey};
+use crate::time::{format_timestamp, now};
+
+/// How often the REST backend syncs in the background.
+const SYNC_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Somewhere to keep the synced snapshot, in the same format as a backup file.
+pub trait SyncBackend {
+    /// The last pushed snapshot, or None if nothing has been pushed yet.
+    async fn pull(&self) -> Result<Option<Backup>, String>;
+    async fn push(&self, snapshot: &Backup) -> Result<(), String>;
+}
+
+/// A self-hosted server that returns the snapshot on GET and stores it on PUT.
+pub struct RestBackend {
+    pub url: String,
+}
+
+impl SyncBackend for RestBackend {
+    async fn pull(&self) -> Result<Option<Backup>, String> {
+        let response = reqwest::get(&self.url)
+            .await
+            .map_err(|err| err.to_string())?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let text = response
+            .error_for_status()
+            .map_err(|err| err.to_string())?
+            .text()
+            .await
+            .map_err(|err| err.to_string())?;
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        parse_backup(&text)
+            .map(Some)
+            .map_err(|errors| errors.join("; "))
+    }
+
+    async fn push(&self, snapshot: &Backup) -> Result<(), String> {
+        reqwest::Client::new()
+            .put(&self.url)
+            .json(snapshot)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// Syncs through a file the user carries between devices: pulls from a
+/// picked file, if any, and pushes by downloading the result.
+pub struct FileBackend {
+    pub contents: Option<String>,
+}
+
+impl SyncBackend for FileBackend {
+    async fn pull(&self) -> Result<Option<Backup>, String> {
+        match &self.contents {
+            Some(text) => parse_backup(text)
+                .map(Some)
+                .map_err(|errors| errors.join("; ")),
+            None => Ok(None),
+        }
+    }
+
+    async fn push(&self, snapshot: &Backup) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(snapshot).map_err(|err| err.to_string())?;
+        download("shiny-dex-sync.json", "application/json", &json);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum BackendKind {
+    #[default]
+    Off,
+    Rest,
+    File,
+}
+
+impl BackendKind {
+    pub const ALL: [BackendKind; 3] = [BackendKind::Off, BackendKind::Rest, BackendKind::File];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BackendKind::Off => "Off",
+            BackendKind::Rest => "Sync server",
+            BackendKind::File => "Sync file",
+        }
+    }
+}
+
+/// What to do when both devices changed since they last synced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Strategy {
+    /// Keep the data of whichever device changed it last.
+    LastWriterWins,
+    /// Combine both, field by field where a hunt changed on both.
+    #[default]
+    MergeByTimestamp,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 2] = [Strategy::MergeByTimestamp, Strategy::LastWriterWins];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Strategy::LastWriterWins => "Last writer wins",
+            Strategy::MergeByTimestamp => "Merge by timestamp",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncSettings {
+    pub backend: BackendKind,
+    pub url: String,
+    pub strategy: Strategy,
+    /// The last synced snapshot, to tell which side changed since.
+    pub base: Option<Backup>,
+    /// When the favourites or hunts were last changed on this device.
+    pub edited_at: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyncStatus {
+    Off,
+    Idle,
+    Syncing,
+    Synced(f64),
+    Failed(String),
+}
+
+fn same_data(a: &Backup, b: &Backup) -> bool {
+    a.collections == b.collections && a.hunts == b.hunts
+}
+
+/// Three way merge of a collection's entries: additions from either side are
+/// kept, removals from either side are applied, and an entry edited on only
+/// one side takes that edit. An entry edited on one side and removed on the
+/// other keeps the edit.
+fn merge_collection(
+    ours: &Collection,
+    theirs: &Collection,
+    base: Option<&Collection>,
+) -> Collection {
+    let base_entry =
+        |pokemon: &str| base.and_then(|b| b.entries.iter().find(|e| e.pokemon == pokemon));
+    let mut entries = Vec::new();
+    for entry in &ours.entries {
+        match theirs.entries.iter().find(|e| e.pokemon == entry.pokemon) {
+            Some(their_entry) if base_entry(&entry.pokemon) == Some(entry) => {
+                entries.push(their_entry.clone())
+            }
+            Some(_) => entries.push(entry.clone()),
+            // Removed on the other device, and left alone on this one.
+            None if base_entry(&entry.pokemon) == Some(entry) => {}
+            None => entries.push(entry.clone()),
+        }
+    }
+    for entry in &theirs.entries {
+        if !ours.contains(&entry.pokemon) && base_entry(&entry.pokemon) != Some(entry) {
+            entries.push(entry.clone());
+        }
+    }
+    Collection {
+        name: ours.name.clone(),
+        entries,
+    }
+}
+
+fn merge_snapshots(base: Option<&Backup>, local: &Backup, remote: &Backup) -> (Collections, Hunts) {
+    let base_collection =
+        |name: &str| base.and_then(|b| b.collections.iter().find(|c| c.name == name));
+    let mut collections = Collections::new();
+    for ours in &local.collections {
+        match remote.collections.iter().find(|c| c.name == ours.name) {
+            Some(theirs) => {
+                collections.push(merge_collection(ours, theirs, base_collection(&ours.name)))
+            }
+            // Removed on the other device.
+            None if base_collection(&ours.name).is_some() => {}
+            None => collections.push(ours.clone()),
+        }
+    }
+    for theirs in &remote.collections {
+        let ours = local.collections.iter().any(|c| c.name == theirs.name);
+        if !ours && base_collection(&theirs.name).is_none() {
+            collections.push(theirs.clone());
+        }
+    }
+
+    let mut hunts = Hunts::new();
+    let names = local
+        .hunts
+        .keys()
+        .chain(remote.hunts.keys())
+        .collect::<BTreeSet<_>>();
+    for name in names {
+        let base_hunt = base.and_then(|b| b.hunts.get(name));
+        let hunt = match (local.hunts.get(name), remote.hunts.get(name)) {
+            (Some(ours), Some(theirs)) => merge_hunt(ours, theirs, base_hunt),
+            // Removed on one device, and left alone on the other.
+            (Some(hunt), None) | (None, Some(hunt)) if base_hunt == Some(hunt) => continue,
+            (Some(hunt), None) | (None, Some(hunt)) => hunt.clone(),
+            (None, None) => continue,
+        };
+        hunts.insert(name.clone(), hunt);
+    }
+    (collections, hunts)
+}
+
+/// Takes whichever side changed a field since the base, or `newer`'s when
+/// both did.
+fn pick<T: Clone + PartialEq>(ours: &T, theirs: &T, base: &T, newer: &T) -> T {
+    if ours == base {
+        theirs.clone()
+    } else if theirs == base {
+        ours.clone()
+    } else {
+        newer.clone()
+    }
+}
+
+/// Three way merge of a hunt changed on both devices. Settings changed on
+/// only one side take that change; otherwise the most recently active side
+/// wins. The encounters, phases and sessions go together, as they only make
+/// sense as a whole.
+fn merge_hunt(ours: &Hunt, theirs: &Hunt, base: Option<&Hunt>) -> Hunt {
+    let newer =
+        if (theirs.last_activity(), theirs.encounters) > (ours.last_activity(), ours.encounters) {
+            theirs
+        } else {
+            ours
+        };
+    let Some(base) = base else {
+        return newer.clone();
+    };
+    let progress = |h: &Hunt| {
+        (
+            h.encounters,
+            h.phases.clone(),
+            h.sessions.clone(),
+            h.session_open,
+        )
+    };
+    let (encounters, phases, sessions, session_open) = pick(
+        &progress(ours),
+        &progress(theirs),
+        &progress(base),
+        &progress(newer),
+    );
+    Hunt {
+        encounters,
+        method: pick(&ours.method, &theirs.method, &base.method, &newer.method),
+        sandwich_level: pick(
+            &ours.sandwich_level,
+            &theirs.sandwich_level,
+            &base.sandwich_level,
+            &newer.sandwich_level,
+        ),
+        shiny_charm: pick(
+            &ours.shiny_charm,
+            &theirs.shiny_charm,
+            &base.shiny_charm,
+            &newer.shiny_charm,
+        ),
+        started_at: pick(
+            &ours.started_at,
+            &theirs.started_at,
+            &base.started_at,
+            &newer.started_at,
+        ),
+        completed_at: pick(
+            &ours.completed_at,
+            &theirs.completed_at,
+            &base.completed_at,
+            &newer.completed_at,
+        ),
+        phases,
+        sessions,
+        session_open,
+    }
+}
+
+/// Combines local and remote data given what was last synced. `local` and
+/// `remote` are stamped with when they were last written. Without a base,
+/// as on a device's first sync, there's no telling what either side
+/// removed, so both are merged whatever the strategy.
+fn resolve(strategy: Strategy, base: Option<&Backup>, local: Backup, remote: Backup) -> Backup {
+    let changed = |snapshot: &Backup| base.is_none_or(|base| !same_data(base, snapshot));
+    match (changed(&local), changed(&remote), strategy) {
+        (false, _, _) => remote,
+        (true, false, _) => local,
+        (true, true, Strategy::LastWriterWins) if base.is_some() => {
+            if remote.exported_at > local.exported_at {
+                remote
+            } else {
+                local
+            }
+        }
+        (true, true, _) => {
+            let (collections, hunts) = merge_snapshots(base, &local, &remote);
+            Backup {
+                collections,
+                hunts,
+                ..local
+            }
+        }
+    }
+}
+
+/// Pulls from the backend, resolves conflicts and pushes the result, which
+/// becomes the new local data and base. `local` is stamped with when it was
+/// last edited, and the pushed result with `now`.
+pub async fn sync<B: SyncBackend>(
+    backend: &B,
+    strategy: Strategy,
+    base: Option<Backup>,
+    local: Backup,
+    now: f64,
+) -> Result<Backup, String> {
+    let mut synced = match backend.pull().await? {
+        Some(remote) => resolve(strategy, base.as_ref(), local, remote),
+        None => local,
+    };
+    synced.exported_at = now;
+    backend.push(&synced).await?;
+    Ok(synced)
+}
+
+/// The signals a sync reads and updates.
+#[derive(Clone, Copy)]
+pub struct Syncer {
+    settings: Signal<SyncSettings>,
+    collections: Signal<Collections>,
+    hunts: Signal<Hunts>,
+    pub status: Signal<SyncStatus>,
+}
+
+impl Syncer {
+    /// Syncs with the configured backend. `file` is the picked file's
+    /// contents, for the file backend.
+    pub async fn run(mut self, file: Option<String>) {
+        if *self.status.peek() == SyncStatus::Syncing {
+            return;
+        }
+        let SyncSettings {
+            backend,
+            url,
+            strategy,
+            base,
+            edited_at,
+        } = self.settings.cloned();
+        let local = Backup {
+            version: BACKUP_VERSION,
+            exported_at: edited_at,
+            collections: self.collections.cloned(),
+            hunts: self.hunts.cloned(),
+        };
+        self.status.set(SyncStatus::Syncing);
+        let result = match backend {
+            BackendKind::Off => {
+                self.status.set(SyncStatus::Off);
+                return;
+            }
+            BackendKind::Rest => sync(&RestBackend { url }, strategy, base, local, now()).await,
+            BackendKind::File => {
+                sync(
+                    &FileBackend { contents: file },
+                    strategy,
+                    base,
+                    local,
+                    now(),
+                )
+                .await
+            }
+        };
+        match result {
+            Ok(synced) => {
+                if *self.collections.peek() != synced.collections {
+                    self.collections.set(synced.collections.clone());
+                }
+                if *self.hunts.peek() != synced.hunts {
+                    self.hunts.set(synced.hunts.clone());
+                }
+                self.settings.write().base = Some(synced);
+                self.status.set(SyncStatus::Synced(now()));
+            }
+            Err(err) => self.status.set(SyncStatus::Failed(err)),
+        }
+    }
+}
+
+/// Provides the syncer to the rest of the app and syncs with a sync server in
+/// the background; call once from the layout, after `use_storage_provider`.
+pub fn use_sync_provider() {
+    let settings = use_stored::<SyncKey>();
+    let status = use_signal(|| match settings.peek().backend {
+        BackendKind::Off => SyncStatus::Off,
+        _ => SyncStatus::Idle,
+    });
+    let syncer = use_context_provider(|| Syncer {
+        settings,
+        collections: use_stored::<CollectionsKey>(),
+        hunts: use_stored::<HuntsKey>(),
+        status,
+    });
+
+    // Stamps local edits for last writer wins. The first run is the data
+    // being loaded, and data matching the base came from a sync.
+    let mut loaded = use_signal(|| false);
+    use_effect(move || {
+        let (collections, hunts) = (syncer.collections.read(), syncer.hunts.read());
+        let mut settings = syncer.settings;
+        let synced = settings
+            .peek()
+            .base
+            .as_ref()
+            .is_some_and(|base| base.collections == *collections && base.hunts == *hunts);
+        if !*loaded.peek() {
+            loaded.set(true);
+        } else if !synced {
+            settings.write().edited_at = now();
+        }
+    });
+
+    let sync_in_background = move || {
+        if settings.peek().backend == BackendKind::Rest {
+            spawn(syncer.run(None));
+        }
+    };
+    use_hook(sync_in_background);
+    use_interval(SYNC_INTERVAL, sync_in_background);
+}
+
+pub fn use_syncer() -> Syncer {
+    use_context()
+}
+
+#[component]
+pub fn SyncBadge() -> Element {
+    let syncer = use_syncer();
+    let (icon, text) = match &*syncer.status.read() {
+        SyncStatus::Off => ("", "Sync off".to_string()),
+        SyncStatus::Idle => ("⟳", "Not synced yet".to_string()),
+        SyncStatus::Syncing => ("⟳", "Syncing…".to_string()),
+        SyncStatus::Synced(at) => ("✓", format!("Synced {}", format_timestamp(*at))),
+        SyncStatus::Failed(_) => ("⚠", "Sync failed".to_string()),
+    };
+    let title = match &*syncer.status.read() {
+        SyncStatus::Failed(err) => err.clone(),
+        _ => String::new(),
+    };
+
+    rsx! {
+        a { href: "/shiny/sync", title: "{title}", "{icon} {text}" }
+    }
+}
+
+#[component]
+pub fn DeviceSync() -> Element {
+    let syncer = use_syncer();
+    let mut settings = syncer.settings;
+    let mut file = use_signal(|| None::<String>);
+
+    let read_file = move |e: FormEvent| async move {
+        let Some(files) = e.files() else {
+            return;
+        };
+        if let Some(name) = files.files().into_iter().next() {
+            file.set(files.read_file_to_string(&name).await);
+        }
+    };
+
+    let backend = settings.read().backend;
+    let strategy = settings.read().strategy;
+
+    rsx! {
+        div { display: "flex", flex_direction: "row", justify_content: "space-between",
+            h1 { "Sync between devices" }
+            h1 { a { href: "/shiny", "Back to the shiny dex" } }
+        }
+        p { "Keeps your favourites and hunts the same on every device you hunt on." }
+        p {
+            "Backend: "
+            select {
+                oninput: move |e| {
+                    let kind = BackendKind::ALL.into_iter().find(|k| k.label() == e.data.value());
+                    settings.write().backend = kind.unwrap_or_default();
+                    let mut status = syncer.status;
+                    status.set(match kind {
+                        Some(BackendKind::Rest) | Some(BackendKind::File) => SyncStatus::Idle,
+                        _ => SyncStatus::Off,
+                    });
+                },
+                for kind in BackendKind::ALL {
+                    option { value: kind.label(), selected: backend == kind, "{kind.label()}" }
+                }
+            }
+        }
+        match backend {
+            BackendKind::Rest => rsx! {
+                p {
+                    "Server URL: "
+                    input {
+                        r#type: "url",
+                        class: "border-2",
+                        width: "400px",
+                        placeholder: "https://example.com/shiny-dex/me.json",
+                        value: "{settings.read().url}",
+                        oninput: move |e| settings.write().url = e.data.value()
+                    }
+                }
+                p {
+                    "Any server that returns the last snapshot for GET and stores the body of a PUT "
+                    "will do. It syncs every few minutes while the site is open."
+                }
+            },
+            BackendKind::File => rsx! {
+                p {
+                    "Pick the file last synced on another device, if any. Syncing downloads the "
+                    "combined data to carry to your other devices."
+                }
+                input { r#type: "file", accept: ".json,application/json", onchange: read_file }
+            },
+            BackendKind::Off => rsx! {},
+        }
+        if backend != BackendKind::Off {
+            p {
+                "When both devices changed: "
+                select {
+                    oninput: move |e| {
+                        let strategy = Strategy::ALL.into_iter().find(|s| s.label() == e.data.value());
+                        settings.write().strategy = strategy.unwrap_or_default();
+                    },
+                    for s in Strategy::ALL {
+                        option { value: s.label(), selected: strategy == s, "{s.label()}" }
+                    }
+                }
+            }
+            p {
+                button {
+                    class: "bg-gray-300 hover:bg-gray-400 font-bold py-2 px-4 rounded",
+                    disabled: *syncer.status.read() == SyncStatus::Syncing,
+                    onclick: move |_| syncer.run(file()),
+                    "Sync now"
+                }
+                span { margin_left: "10px", SyncBadge {} }
+            }
+            if let SyncStatus::Failed(err) = &*syncer.status.read() {
+                p { color: "#D32F2F", "{err}" }
+            }
+        }
+        footer::Footer {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shiny_dex::favourites::FavouriteEntry;
+    use crate::shiny_dex::hunt::HuntMethod;
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::pin::pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+
+    /// Stands in for a sync server.
+    struct MemoryBackend(RefCell<Option<Backup>>);
+
+    impl SyncBackend for MemoryBackend {
+        async fn pull(&self) -> Result<Option<Backup>, String> {
+            Ok(self.0.borrow().clone())
+        }
+
+        async fn push(&self, snapshot: &Backup) -> Result<(), String> {
+            self.0.replace(Some(snapshot.clone()));
+            Ok(())
+        }
+    }
+
+    /// Runs a future that never waits, which the memory backend's don't.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future was pending"),
+        }
+    }
+
+    fn snapshot(favourites: &[&str], hunts: &[(&str, Hunt)]) -> Backup {
+        Backup {
+            version: BACKUP_VERSION,
+            exported_at: 0.0,
+            collections: vec![Collection {
+                name: "Favourites".to_string(),
+                entries: favourites.iter().map(|p| FavouriteEntry::new(p)).collect(),
+            }],
+            hunts: hunts
+                .iter()
+                .map(|(name, hunt)| (name.to_string(), hunt.clone()))
+                .collect(),
+        }
+    }
+
+    fn hunt(encounters: i64, last_activity: f64) -> Hunt {
+        let mut hunt = Hunt::new(0.0);
+        for _ in 0..encounters {
+            hunt.encounter(last_activity);
+        }
+        hunt
+    }
+
+    fn favourites(snapshot: &Backup) -> Vec<&str> {
+        snapshot.collections[0]
+            .entries
+            .iter()
+            .map(|e| e.pokemon.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn first_sync_pushes_local_data() {
+        let backend = MemoryBackend(RefCell::new(None));
+        let local = snapshot(&["eevee"], &[]);
+
+        let synced = block_on(sync(&backend, Strategy::default(), None, local, 10.0)).unwrap();
+
+        assert_eq!(favourites(&synced), vec!["eevee"]);
+        assert_eq!(backend.0.borrow().as_ref(), Some(&synced));
+    }
+
+    #[test]
+    fn unchanged_local_data_takes_remote() {
+        let base = snapshot(&["eevee"], &[]);
+        let remote = snapshot(&["eevee", "ralts"], &[]);
+        let backend = MemoryBackend(RefCell::new(Some(remote)));
+
+        let synced = block_on(sync(
+            &backend,
+            Strategy::LastWriterWins,
+            Some(base.clone()),
+            base,
+            10.0,
+        ))
+        .unwrap();
+
+        assert_eq!(favourites(&synced), vec!["eevee", "ralts"]);
+    }
+
+    #[test]
+    fn first_sync_merges_with_existing_remote_data() {
+        let remote = snapshot(&["ralts"], &[("ralts", hunt(40, 40.0))]);
+        let backend = MemoryBackend(RefCell::new(Some(remote)));
+        let mut local = snapshot(&[], &[]);
+        local.exported_at = 100.0;
+
+        let synced =
+            block_on(sync(&backend, Strategy::LastWriterWins, None, local, 110.0)).unwrap();
+
+        assert_eq!(favourites(&synced), vec!["ralts"]);
+        assert_eq!(synced.hunts["ralts"].encounters, 40);
+    }
+
+    #[test]
+    fn last_writer_wins_keeps_the_later_edit() {
+        let base = snapshot(&["eevee"], &[]);
+        let mut local = snapshot(&["eevee", "gible"], &[]);
+        let mut remote = snapshot(&["ralts"], &[]);
+
+        local.exported_at = 20.0;
+        remote.exported_at = 10.0;
+        let resolved = resolve(
+            Strategy::LastWriterWins,
+            Some(&base),
+            local.clone(),
+            remote.clone(),
+        );
+        assert_eq!(favourites(&resolved), vec!["eevee", "gible"]);
+
+        local.exported_at = 5.0;
+        let resolved = resolve(Strategy::LastWriterWins, Some(&base), local, remote);
+        assert_eq!(favourites(&resolved), vec!["ralts"]);
+    }
+
+    #[test]
+    fn merge_applies_additions_and_removals_from_both_sides() {
+        let base = snapshot(&["eevee", "pikachu"], &[]);
+        let local = snapshot(&["eevee", "pikachu", "gible"], &[]);
+        let remote = snapshot(&["eevee", "ralts"], &[]);
+
+        let resolved = resolve(Strategy::MergeByTimestamp, Some(&base), local, remote);
+
+        assert_eq!(favourites(&resolved), vec!["eevee", "gible", "ralts"]);
+    }
+
+    #[test]
+    fn merge_keeps_entries_edited_on_one_side_and_removed_on_the_other() {
+        let base = snapshot(&["eevee", "ralts"], &[]);
+        let mut local = snapshot(&["eevee", "ralts"], &[]);
+        local.collections[0].entries[0].note = "jolly".to_string();
+        let mut remote = snapshot(&["ralts"], &[]);
+        remote.collections[0].entries[0].note = "timid".to_string();
+
+        let resolved = resolve(Strategy::MergeByTimestamp, Some(&base), local, remote);
+
+        let entries = &resolved.collections[0].entries;
+        assert_eq!(favourites(&resolved), vec!["eevee", "ralts"]);
+        assert_eq!(entries[0].note, "jolly");
+        assert_eq!(entries[1].note, "timid");
+    }
+
+    #[test]
+    fn merge_keeps_the_most_recently_active_hunt() {
+        let base = snapshot(&[], &[("ralts", hunt(1, 1.0)), ("gible", hunt(1, 1.0))]);
+        let local = snapshot(&[], &[("ralts", hunt(5, 50.0)), ("gible", hunt(2, 20.0))]);
+        let remote = snapshot(&[], &[("ralts", hunt(3, 30.0)), ("gible", hunt(9, 90.0))]);
+
+        let resolved = resolve(Strategy::MergeByTimestamp, Some(&base), local, remote);
+
+        assert_eq!(resolved.hunts["ralts"].encounters, 5);
+        assert_eq!(resolved.hunts["gible"].encounters, 9);
+    }
+
+    #[test]
+    fn merge_keeps_settings_changed_on_the_less_active_side() {
+        let base = snapshot(&[], &[("ralts", hunt(1, 1.0))]);
+        let mut ours = hunt(1, 1.0);
+        ours.method = HuntMethod::Outbreak;
+        ours.sandwich_level = 3;
+        let local = snapshot(&[], &[("ralts", ours)]);
+        let mut theirs = hunt(8, 80.0);
+        theirs.shiny_charm = true;
+        let remote = snapshot(&[], &[("ralts", theirs)]);
+
+        let resolved = resolve(Strategy::MergeByTimestamp, Some(&base), local, remote);
+
+        let ralts = &resolved.hunts["ralts"];
+        assert_eq!(ralts.encounters, 8);
+        assert_eq!(ralts.method, HuntMethod::Outbreak);
+        assert_eq!(ralts.sandwich_level, 3);
+        assert!(ralts.shiny_charm);
+    }
+
+    #[test]
+    fn merge_drops_hunts_removed_on_one_side() {
+        let base = snapshot(&[], &[("ralts", hunt(1, 1.0))]);
+        let local = snapshot(&["eevee"], &[]);
+        let remote = snapshot(&[], &[("ralts", hunt(1, 1.0))]);
+
+        let resolved = resolve(Strategy::MergeByTimestamp, Some(&base), local, remote);
+
+        assert!(resolved.hunts.is_empty());
+    }
+
+    /// A minimal sync server: answers GET with the body of the last PUT, or
+    /// 404 before there's been one. Returns its URL.
+    fn serve(requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/me.json", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let stored = Arc::new(Mutex::new(None::<String>));
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let (status, body) = if request_line.starts_with("PUT") {
+                    *stored.lock().unwrap() = Some(String::from_utf8(body).unwrap());
+                    ("200 OK", String::new())
+                } else {
+                    match stored.lock().unwrap().clone() {
+                        Some(body) => ("200 OK", body),
+                        None => ("404 Not Found", String::new()),
+                    }
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn rest_backend_round_trips_through_a_server() {
+        let backend = RestBackend { url: serve(3) };
+        let snapshot = snapshot(&["eevee"], &[("ralts", hunt(3, 30.0))]);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            assert_eq!(backend.pull().await, Ok(None));
+            backend.push(&snapshot).await.unwrap();
+            assert_eq!(backend.pull().await, Ok(Some(snapshot.clone())));
+        });
+    }
+}
@@ -0,0 +1,32 @@
+use js_sys::wasm_bindgen::JsValue;
+use js_sys::Date;
+
+/// Milliseconds since the unix epoch, as reported by the browser.
+pub fn now() -> f64 {
+    Date::now()
+}
+
+/// Formats a timestamp from `now` in the user's locale.
+pub fn format_timestamp(timestamp: f64) -> String {
+    Date::new(&JsValue::from_f64(timestamp))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+/// Formats a duration in milliseconds as `h:mm:ss`.
+pub fn format_duration(duration: f64) -> String {
+    let seconds = (duration / 1000.0).max(0.0) as i64;
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// Formats a timestamp from `now` as ISO 8601, e.g. for exports.
+pub fn format_iso(timestamp: f64) -> String {
+    Date::new(&JsValue::from_f64(timestamp))
+        .to_iso_string()
+        .into()
+}